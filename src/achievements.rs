@@ -0,0 +1,225 @@
+use crate::game::frame::Mark;
+use crate::game::Game;
+
+static TURKEY: u8 = 3;
+static DUTCH_SCORE: u16 = 200;
+static SERIES_HONOR_SCORE: u16 = 800;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Achievement {
+    // three or more strikes in a row (a turkey), from `frame`
+    StrikeChain { frame: u8, strikes: u8 },
+    // a strike or a spare in every frame
+    CleanGame,
+    // strike and spare frames alternating for a 200 game
+    Dutch200,
+    // strikes only
+    PerfectGame,
+    SplitConversion { frame: u8 },
+    // series total over 800 pins
+    HonorSeries { score: u16 },
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SeriesAchievements {
+    // feats of every game, in game order
+    pub games: Vec<Vec<Achievement>>,
+    // feats of the series as a whole
+    pub series: Vec<Achievement>,
+}
+
+// Feats of a single game, in frame order
+pub fn game_achievements(game: &Game) -> Vec<Achievement> {
+    let mut achievements = strike_chains(game);
+
+    achievements.extend(
+        game.frames()
+            .iter()
+            .filter(|frame| frame.is_spare())
+            .filter(|frame| {
+                frame
+                    .first_ball_leave()
+                    .is_some_and(|leave| leave.is_split())
+            })
+            .map(|frame| Achievement::SplitConversion {
+                frame: frame.number,
+            }),
+    );
+
+    if !game.closed() {
+        return achievements;
+    }
+
+    let frames = game.frames();
+    let clean = frames.iter().all(|frame| !frame.is_open());
+    if clean {
+        achievements.push(Achievement::CleanGame);
+    }
+
+    let alternating = frames
+        .windows(2)
+        .all(|pair| pair[0].is_strike() != pair[1].is_strike());
+    if clean && alternating && game.score() == DUTCH_SCORE {
+        achievements.push(Achievement::Dutch200);
+    }
+
+    if frames
+        .iter()
+        .flat_map(|frame| frame.marks())
+        .all(|mark| mark == Mark::Strike)
+    {
+        achievements.push(Achievement::PerfectGame);
+    }
+
+    achievements
+}
+
+// Feats of every game of a series, and of the series itself
+pub fn series_achievements(games: &[Game]) -> SeriesAchievements {
+    let mut achievements = SeriesAchievements {
+        games: games.iter().map(game_achievements).collect(),
+        ..Default::default()
+    };
+
+    let score: u16 = games.iter().map(|game| game.score()).sum();
+    if score >= SERIES_HONOR_SCORE && games.iter().all(|game| game.closed()) {
+        achievements.series.push(Achievement::HonorSeries { score });
+    }
+
+    achievements
+}
+
+fn strike_chains(game: &Game) -> Vec<Achievement> {
+    let mut chains = vec![];
+    let mut chain: Option<(u8, u8)> = None;
+
+    let marks = game
+        .frames()
+        .iter()
+        .flat_map(|frame| frame.marks().into_iter().map(|mark| (frame.number, mark)));
+
+    for (frame, mark) in marks.chain([(0, Mark::Pins(0))]) {
+        chain = match (mark, chain) {
+            (Mark::Strike, Some((first, strikes))) => Some((first, strikes + 1)),
+            (Mark::Strike, None) => Some((frame, 1)),
+            (_, Some((first, strikes))) => {
+                if strikes >= TURKEY {
+                    chains.push(Achievement::StrikeChain {
+                        frame: first,
+                        strikes,
+                    });
+                }
+                None
+            }
+            (_, None) => None,
+        };
+    }
+
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::achievements::*;
+    use crate::game::pin_set::PinSet;
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+
+    #[test]
+    fn turkey() {
+        let game = play_game(&[10, 10, 10, 4, 5], Rules::new());
+
+        assert_eq!(
+            game_achievements(&game),
+            vec![Achievement::StrikeChain {
+                frame: 1,
+                strikes: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn two_strikes_are_not_a_chain() {
+        let game = play_game(&[10, 10, 4, 5], Rules::new());
+
+        assert!(game_achievements(&game).is_empty());
+    }
+
+    #[test]
+    fn perfect_game() {
+        let game = play_game(&[10; 12], Rules::new());
+
+        assert_eq!(
+            game_achievements(&game),
+            vec![
+                Achievement::StrikeChain {
+                    frame: 1,
+                    strikes: 12
+                },
+                Achievement::CleanGame,
+                Achievement::PerfectGame
+            ]
+        );
+    }
+
+    #[test]
+    fn dutch_200() {
+        let rolls: Vec<u8> = vec![10, 5, 5, 10, 5, 5, 10, 5, 5, 10, 5, 5, 10, 5, 5, 10];
+        let game = play_game(&rolls, Rules::new());
+
+        assert_eq!(game.score(), 200);
+        assert_eq!(
+            game_achievements(&game),
+            vec![Achievement::CleanGame, Achievement::Dutch200]
+        );
+    }
+
+    #[test]
+    fn split_conversion() {
+        let mut game = Game::new(Rules::new());
+        game.roll_pin_set(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9]));
+        game.roll_pin_set(PinSet::from_pins(&[7, 10]));
+        game.roll_pin_set(PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9]));
+        game.roll_pin_set(PinSet::from_pins(&[7]));
+
+        assert_eq!(
+            game_achievements(&game),
+            vec![Achievement::SplitConversion { frame: 1 }]
+        );
+    }
+
+    #[test]
+    fn honor_series() {
+        let games = vec![
+            play_game(&[10; 12], Rules::new()),
+            play_game(&[10; 12], Rules::new()),
+            play_game(
+                &[10, 10, 10, 10, 10, 10, 10, 10, 10, 5, 5, 10],
+                Rules::new(),
+            ),
+        ];
+
+        let achievements = series_achievements(&games);
+
+        assert_eq!(achievements.games.len(), 3);
+        assert!(achievements.games[0].contains(&Achievement::PerfectGame));
+        assert_eq!(
+            achievements.series,
+            vec![Achievement::HonorSeries { score: 875 }]
+        );
+    }
+
+    #[test]
+    fn no_honor_series() {
+        let games = vec![
+            play_game(&[10; 12], Rules::new()),
+            play_game(&[10; 12], Rules::new()),
+            play_game(&[5; 21], Rules::new()),
+        ];
+
+        let achievements = series_achievements(&games);
+
+        assert_eq!(achievements.games.len(), 3);
+        assert!(achievements.series.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::game::rules::{Rules, Variant};
+use crate::game::Game;
+use crate::BoxResult;
+
+// Layout: version, rules fingerprint (4 bytes, little endian), number of
+// rolls (2 bytes, little endian: enough for 255 frames of 255 rolls), then
+// the rolls packed with as few bits as the biggest rack needs
+static VERSION: u8 = 1;
+static HEADER_LEN: usize = 7;
+
+static FNV_OFFSET_BASIS: u32 = 0x811c9dc5;
+static FNV_PRIME: u32 = 0x01000193;
+
+// Stable across builds and platforms: archives outlive the binary
+pub fn fingerprint(rules: &Rules) -> u32 {
+    let variant = match rules.variant {
+        Variant::Standard => 0,
+        Variant::LowBall => 1,
+        Variant::SparesOnly => 2,
+    };
+    let mut bytes = vec![
+        rules.rolls_per_frame,
+        rules.max_frames,
+        rules.initial_pins,
+        rules.pins_increment_per_frame,
+        rules.fill_balls as u8,
+        variant,
+        rules.auto_strike_frames.len() as u8,
+    ];
+    bytes.extend(&rules.auto_strike_frames);
+
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(FNV_PRIME)
+    })
+}
+
+pub fn encode(game: &Game) -> Vec<u8> {
+    let rolls = game.rolls();
+    let width = roll_width(game.rules());
+
+    let mut bytes = vec![VERSION];
+    bytes.extend(fingerprint(game.rules()).to_le_bytes());
+    bytes.extend((rolls.len() as u16).to_le_bytes());
+
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for pins in rolls {
+        buffer |= (pins as u32) << buffered;
+        buffered += width;
+        while buffered >= 8 {
+            bytes.push(buffer as u8);
+            buffer >>= 8;
+            buffered -= 8;
+        }
+    }
+    if buffered > 0 {
+        bytes.push(buffer as u8);
+    }
+    bytes
+}
+
+// Replays the archived rolls on a new game played with `rules`
+pub fn decode(bytes: &[u8], rules: &Rules) -> BoxResult<Game> {
+    if bytes.len() < HEADER_LEN {
+        bail!("archive too short");
+    }
+    if bytes[0] != VERSION {
+        bail!("unsupported archive version {}", bytes[0]);
+    }
+    if u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]) != fingerprint(rules) {
+        bail!("archive recorded with different rules");
+    }
+
+    let count = u16::from_le_bytes([bytes[5], bytes[6]]) as usize;
+    let width = roll_width(rules);
+    let mask = (1u32 << width) - 1;
+    let mut packed = bytes[HEADER_LEN..].iter();
+
+    let mut game = Game::new(rules.clone());
+    let mut buffer: u32 = 0;
+    let mut buffered = 0;
+    for roll in 0..count {
+        while buffered < width {
+            match packed.next() {
+                Some(&byte) => buffer |= (byte as u32) << buffered,
+                None => bail!("archive truncated at roll {}", roll + 1),
+            }
+            buffered += 8;
+        }
+        let pins = (buffer & mask) as u8;
+        buffer >>= width;
+        buffered -= width;
+
+        if game.closed() || !game.roll(pins) {
+            bail!("illegal roll {} in archive", roll + 1);
+        }
+    }
+    Ok(game)
+}
+
+// Bits needed by the biggest rack the rules can set up
+fn roll_width(rules: &Rules) -> u32 {
+    (u16::BITS - rules.biggest_rack().leading_zeros()).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::archive::*;
+    use crate::game::play_game;
+
+    #[test]
+    fn perfect_game_size() {
+        let game = play_game(&[10; 12], Rules::new());
+
+        assert_eq!(encode(&game).len(), HEADER_LEN + 6);
+    }
+
+    #[test]
+    fn round_trip() {
+        let rules = Rules::new();
+        let rolls = [10, 7, 3, 9, 0, 10, 0, 8, 8, 2, 0, 6, 10, 10, 10, 8, 1];
+        let game = play_game(&rolls, rules.clone());
+
+        let decoded = decode(&encode(&game), &rules).unwrap();
+
+        assert_eq!(decoded.rolls(), rolls.to_vec());
+        assert_eq!(decoded.score(), game.score());
+        assert_eq!(decoded.frames(), game.frames());
+    }
+
+    #[test]
+    fn round_trip_with_bigger_racks() {
+        let mut rules = Rules::new();
+        rules.pins_increment_per_frame = 1;
+        let rolls = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 19, 0];
+        let game = play_game(&rolls, rules.clone());
+
+        let decoded = decode(&encode(&game), &rules).unwrap();
+
+        assert_eq!(roll_width(&rules), 5);
+        assert_eq!(decoded.rolls(), rolls.to_vec());
+        assert_eq!(decoded.score(), game.score());
+    }
+
+    #[test]
+    fn round_trip_with_credited_frames() {
+        let rules = Rules::three_six_nine();
+        let game = play_game(&[1, 1, 1, 1, 3, 4], rules.clone());
+
+        let decoded = decode(&encode(&game), &rules).unwrap();
+
+        assert_eq!(decoded.score(), 28);
+        assert_eq!(decoded.frames(), game.frames());
+    }
+
+    #[test]
+    fn round_trip_with_many_rolls() {
+        let mut rules = Rules::new();
+        rules.max_frames = 200;
+        let game = play_game(&[1; 400], rules.clone());
+
+        let decoded = decode(&encode(&game), &rules).unwrap();
+
+        assert_eq!(decoded.rolls().len(), 400);
+        assert_eq!(decoded.score(), 400);
+        assert!(decoded.closed());
+    }
+
+    #[test]
+    fn different_rules() {
+        let game = play_game(&[10; 12], Rules::new());
+
+        let decoded = decode(&encode(&game), &Rules::three_six_nine());
+
+        assert_eq!(
+            decoded.unwrap_err().to_string(),
+            "archive recorded with different rules"
+        );
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut bytes = encode(&play_game(&[10; 12], Rules::new()));
+        bytes[0] = 2;
+
+        let decoded = decode(&bytes, &Rules::new());
+
+        assert_eq!(
+            decoded.unwrap_err().to_string(),
+            "unsupported archive version 2"
+        );
+    }
+
+    #[test]
+    fn truncated_archive() {
+        let mut bytes = encode(&play_game(&[10; 12], Rules::new()));
+        bytes.pop();
+
+        let decoded = decode(&bytes, &Rules::new());
+
+        assert_eq!(
+            decoded.unwrap_err().to_string(),
+            "archive truncated at roll 11"
+        );
+    }
+}
@@ -2,54 +2,237 @@
  *  Public functions
  */
 
+use serde::{Deserialize, Serialize};
+
+use crate::BoxResult;
+
 mod striking_bonuses;
 use striking_bonuses::*;
 
-pub(crate) mod rules; // TODO: pub(crate)???
-use rules::Rules;
+pub mod events;
+use events::{GameEvent, GameListener};
+
+pub mod frame;
+use frame::Frame;
+
+pub mod pin_set;
+use pin_set::PinSet;
+
+pub mod prizes;
+use prizes::{Prize, PrizeEvaluator, RollContext};
 
-#[derive(Debug, Default)]
+pub mod rules;
+use rules::{Rules, Variant};
+
+pub mod scoring;
+
+pub mod validation;
+
+// Listeners, evaluators and pending events are not saved: attach them
+// again after loading
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Game {
     score: u16,
     pins: u8,
-    total_rolls: u8,
+    total_rolls: u16,
     current_frame: u8,
     remaining_rolls_in_frame: u8,
     frame_scores: Vec<u8>,
     sparing: u8,
     striking_rolls: StrikingBonus,
     rules: Rules,
+    #[serde(skip)]
+    evaluators: Vec<Box<dyn PrizeEvaluator>>,
+    prizes: Vec<Prize>,
+    #[serde(skip)]
+    listeners: Vec<Box<dyn GameListener>>,
+    #[serde(skip)]
+    events: Vec<GameEvent>,
+    frames: Vec<Frame>,
 }
 
 impl Game {
     pub fn new(rules: Rules) -> Game {
-        Game {
+        let mut game = Game {
             current_frame: 1,
             remaining_rolls_in_frame: rules.rolls_per_frame,
             pins: rules.initial_pins,
             striking_rolls: StrikingBonus::new(),
             frame_scores: vec![],
+            frames: vec![Frame::new(1, rules.initial_pins)],
             rules,
             ..Default::default()
-        }
+        };
+        game.auto_strike();
+        game
     }
 
     pub fn closed(&self) -> bool {
         self.last_frame()
             && self.rolls_in_frame_are_over()
-            && self.sparing_is_over()
-            && self.striking_rolls.striking_rolls_are_over()
+            && (!self.rules.fill_balls
+                || (self.sparing_is_over() && self.striking_rolls.striking_rolls_are_over()))
     }
 
     pub fn score(&self) -> u16 {
         self.score
     }
 
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn current_frame(&self) -> u8 {
+        self.current_frame
+    }
+
+    // Frames rolled so far, the current one included
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn standing_pins(&self) -> u8 {
+        self.frames
+            .last()
+            .map_or(self.pins, |frame| frame.standing_pins())
+    }
+
+    // Pins of every roll, frames credited as strikes excluded
+    pub fn rolls(&self) -> Vec<u8> {
+        self.frames
+            .iter()
+            .filter(|frame| !frame.credited)
+            .flat_map(|frame| frame.rolls.iter().chain(frame.fill_balls.iter()))
+            .copied()
+            .collect()
+    }
+
     pub fn roll(&mut self, pins: u8) -> bool {
+        self.roll_and_evaluate(pins, None)
+    }
+
+    // Roll with the pins actually knocked down, for pin-level prizes
+    pub fn roll_pin_set(&mut self, knocked: PinSet) -> bool {
+        self.roll_and_evaluate(knocked.count(), Some(knocked))
+    }
+
+    pub fn attach(&mut self, evaluator: Box<dyn PrizeEvaluator>) {
+        self.evaluators.push(evaluator);
+    }
+
+    pub fn prizes(&self) -> &[Prize] {
+        &self.prizes
+    }
+
+    pub fn subscribe(&mut self, listener: Box<dyn GameListener>) {
+        self.listeners.push(listener);
+    }
+
+    // Game in progress, bonus state included, to be resumed with `from_json`
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    // A save cut short or edited by hand is refused rather than left to
+    // panic on a later roll
+    pub fn from_json(json: &str) -> BoxResult<Game> {
+        let game: Game = serde_json::from_str(json)?;
+        game.verify()?;
+        Ok(game)
+    }
+
+    // The saved state must be the one its rolls lead to: the game is
+    // rebuilt from them and compared field by field
+    pub(crate) fn verify(&self) -> BoxResult<()> {
+        let rules = &self.rules;
+        if rules.rolls_per_frame == 0
+            || rules.max_frames == 0
+            || rules.biggest_rack() > u8::MAX as u16
+        {
+            bail!("invalid saved game: rules out of range");
+        }
+
+        let mut rebuilt = Game::new(rules.clone());
+        for (roll, pins) in self.rolls().into_iter().enumerate() {
+            if rebuilt.closed() || !rebuilt.roll(pins) {
+                bail!("invalid saved game: roll {} refused", roll + 1);
+            }
+        }
+
+        if rebuilt.score != self.score
+            || rebuilt.pins != self.pins
+            || rebuilt.total_rolls != self.total_rolls
+            || rebuilt.current_frame != self.current_frame
+            || rebuilt.remaining_rolls_in_frame != self.remaining_rolls_in_frame
+            || rebuilt.frame_scores != self.frame_scores
+            || rebuilt.sparing != self.sparing
+            || rebuilt.striking_rolls != self.striking_rolls
+            || rebuilt.frames != self.frames
+        {
+            bail!("invalid saved game: state does not match its rolls");
+        }
+        Ok(())
+    }
+
+    /*
+     *  Private functions
+     */
+
+    fn roll_and_evaluate(&mut self, pins: u8, knocked: Option<PinSet>) -> bool {
+        let roll = RollContext {
+            frame: self.current_frame,
+            first_ball: self.is_first_roll_in_frame(),
+            pins,
+            rack: self.pins,
+            knocked,
+        };
+
+        if !self.accept_roll(pins, knocked) {
+            return false;
+        }
+
+        for evaluator in self.evaluators.iter_mut() {
+            if let Some(prize) = evaluator.evaluate(&roll) {
+                self.prizes.push(prize);
+            }
+        }
+
+        if self.closed() {
+            self.emit(GameEvent::FrameCompleted {
+                frame: self.current_frame,
+            });
+            self.emit(GameEvent::GameClosed { score: self.score });
+        }
+        self.notify();
+
+        true
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        if !self.listeners.is_empty() {
+            self.events.push(event);
+        }
+    }
+
+    fn notify(&mut self) {
+        let events = std::mem::take(&mut self.events);
+        for listener in self.listeners.iter_mut() {
+            for event in &events {
+                listener.on_event(event);
+            }
+        }
+    }
+
+    fn accept_roll(&mut self, pins: u8, knocked: Option<PinSet>) -> bool {
         if self.closed() {
             panic!("Game already closed.");
         }
 
+        if self.rules.variant == Variant::LowBall && pins == 0 {
+            // gutter balls are forbidden in low-ball
+            return false;
+        }
+
         // bonus rolls is only for last frame
         let is_a_bonus_roll = self.last_frame_bonus();
 
@@ -58,27 +241,57 @@ impl Game {
             return false;
         }
 
+        if (is_a_bonus_roll || self.is_first_roll_in_frame()) && pins > self.standing_pins() {
+            // first and fill balls knock down only the pins standing, as
+            // the validator requires
+            return false;
+        }
+
         self.total_rolls += 1;
         self.frame_scores.push(pins);
+        if let Some(frame) = self.frames.last_mut() {
+            frame.record(pins, knocked, is_a_bonus_roll);
+        }
+        self.emit(GameEvent::RollAccepted {
+            frame: self.current_frame,
+            pins,
+        });
 
         if !is_a_bonus_roll {
             self.add_score(pins);
         }
 
+        let mut bonus = 0;
         if self.have_sparing() {
             self.add_score(pins);
+            bonus += pins as u16;
         }
 
         let striking_rolls_bonus = self.striking_rolls.get_striking_rolls_bonus();
         if striking_rolls_bonus > 0 {
             self.add_score(pins * striking_rolls_bonus as u8);
             self.striking_rolls.decrement_striking_rolls_bonus();
+            bonus += pins as u16 * striking_rolls_bonus as u16;
+        }
+
+        if bonus > 0 {
+            self.emit(GameEvent::BonusApplied { pins: bonus });
         }
 
         if self.is_first_roll_in_frame() && self.is_strike(pins) {
             // strike!
             self.add_striking();
             self.remaining_rolls_in_frame = 0;
+            self.emit(GameEvent::Strike {
+                frame: self.current_frame,
+            });
+        }
+
+        // a spare already made is only kept alive by further rolls of 0 pins
+        if pins > 0 && self.is_not_first_roll_in_frame() && self.is_full_score() {
+            self.emit(GameEvent::Spare {
+                frame: self.current_frame,
+            });
         }
 
         self.update_sparing();
@@ -87,10 +300,6 @@ impl Game {
         true
     }
 
-    /*
-     *  Private functions
-     */
-
     fn add_score(&mut self, pins: u8) {
         self.score += pins as u16;
     }
@@ -101,6 +310,7 @@ impl Game {
 
     fn last_frame_bonus(&self) -> bool {
         self.last_frame()
+            && self.rules.fill_balls
             && self.rolls_in_frame_are_over()
             && (self.striking_rolls.has_striking_rolls() || self.have_sparing())
     }
@@ -124,14 +334,33 @@ impl Game {
     }
 
     fn set_to_next_frame(&mut self) {
+        self.emit(GameEvent::FrameCompleted {
+            frame: self.current_frame,
+        });
         self.remaining_rolls_in_frame = self.rules.rolls_per_frame;
         self.current_frame += 1;
         self.frame_scores = vec![];
         self.pins += self.rules.pins_increment_per_frame;
+        self.frames.push(Frame::new(self.current_frame, self.pins));
+        self.auto_strike();
+    }
+
+    // Frames credited as strikes are rolled on their own
+    fn auto_strike(&mut self) {
+        if self.rules.auto_strike_frames.contains(&self.current_frame) {
+            if let Some(frame) = self.frames.last_mut() {
+                frame.credited = true;
+            }
+            self.accept_roll(self.pins, None);
+        }
     }
 
     fn update_frame_after_roll(&mut self, pins: u8) {
         self.decrement_rolls_in_frame();
+        if self.rules.variant == Variant::LowBall && self.is_full_score() {
+            // no gutter ball can follow a low-ball spare: the frame is over
+            self.remaining_rolls_in_frame = 0;
+        }
         if !self.last_frame() && (self.is_strike(pins) || self.rolls_in_frame_are_over()) {
             self.set_to_next_frame();
         }
@@ -169,7 +398,9 @@ impl Game {
     }
 
     fn add_striking(&mut self) {
-        self.striking_rolls.increment_striking_rolls_bonus();
+        if self.rules.variant != Variant::SparesOnly {
+            self.striking_rolls.increment_striking_rolls_bonus();
+        }
     }
 
     fn is_strike(&self, pins: u8) -> bool {
@@ -177,6 +408,16 @@ impl Game {
     }
 }
 
+// Used in tests
+#[cfg(test)]
+pub(crate) fn play_game(rolls: &[u8], rules: Rules) -> Game {
+    let mut game = Game::new(rules);
+    for pins in rolls {
+        game.roll(*pins);
+    }
+    game
+}
+
 /*
  *  Tests
  */
@@ -184,6 +425,7 @@ impl Game {
 #[cfg(test)]
 mod normal_game {
     use crate::game::striking_bonuses::*;
+    use crate::game::validation::{validate, Validation};
     use crate::game::*;
 
     #[test]
@@ -195,9 +437,9 @@ mod normal_game {
         assert_eq!(game.pins, 10);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 2);
-        assert_eq!(game.frame_scores, vec![]);
+        assert!(game.frame_scores.is_empty());
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.striking_rolls.striking_rolls_are_over(), true);
+        assert!(game.striking_rolls.striking_rolls_are_over());
         assert_eq!(game.rules.rolls_per_frame, 2);
         assert_eq!(game.rules.max_frames, 10);
     }
@@ -210,7 +452,7 @@ mod normal_game {
         assert_eq!(game.score, 8);
         assert_eq!(game.current_frame, 2);
         assert_eq!(game.remaining_rolls_in_frame, 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -221,7 +463,28 @@ mod normal_game {
         assert_eq!(game.score, 9);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 1);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
+    }
+
+    #[test]
+    fn too_many_pins_on_first_ball() {
+        let mut game = Game::new(Rules::new());
+
+        assert!(!game.roll(11));
+        assert_eq!(game.total_rolls, 0);
+    }
+
+    #[test]
+    fn fill_ball_over_standing_pins() {
+        let mut rolls: Vec<u8> = vec![0; 18];
+        rolls.extend([10, 7]);
+        let mut game = play_this_game(&rolls);
+
+        assert!(!game.roll(4));
+        assert!(!game.closed());
+        assert!(game.roll(3));
+        assert_eq!(game.score, 20);
+        assert_eq!(validate(&game.rolls(), &game.rules), Validation::Complete);
     }
     #[test]
 
@@ -230,7 +493,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 0);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -238,7 +501,7 @@ mod normal_game {
         let rolls: Vec<u8> = vec![0; 19];
         let game = play_this_game(&rolls);
 
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -247,7 +510,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 300);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -256,7 +519,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 270);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -265,7 +528,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 20);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -275,7 +538,7 @@ mod normal_game {
 
         assert_eq!(game.score, 10);
         assert_eq!(game.sparing, 1);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -285,8 +548,8 @@ mod normal_game {
 
         assert_eq!(game.score, 21);
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.frame_scores, vec![]);
-        assert_eq!(game.closed(), false);
+        assert!(game.frame_scores.is_empty());
+        assert!(!game.closed());
     }
 
     #[test]
@@ -296,7 +559,7 @@ mod normal_game {
 
         assert_eq!(game.score, 9);
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -306,7 +569,7 @@ mod normal_game {
 
         assert_eq!(game.score, 10);
         assert_eq!(first_slot(&game.striking_rolls), 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -316,7 +579,7 @@ mod normal_game {
 
         assert_eq!(game.score, 14);
         assert_eq!(first_slot(&game.striking_rolls), 0);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -325,7 +588,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.current_frame, 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -334,7 +597,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 34);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -343,7 +606,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 33);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -352,7 +615,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 50);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -361,7 +624,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 140);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -370,7 +633,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 145);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -379,7 +642,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 150);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -388,7 +651,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 30);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -397,7 +660,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 14);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -406,7 +669,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 35);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -415,7 +678,7 @@ mod normal_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 60);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     /*
@@ -511,10 +774,10 @@ mod normal_game {
     }
 
     fn test_example(score: u16, rolls: &Vec<u8>) {
-        let game = play_this_game(&rolls);
+        let game = play_this_game(rolls);
 
         assert_eq!(game.score, score);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     fn play_this_game(rolls: &Vec<u8>) -> Game {
@@ -541,9 +804,9 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.total_rolls, 0);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 3);
-        assert_eq!(game.frame_scores, vec![]);
+        assert!(game.frame_scores.is_empty());
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.striking_rolls.striking_rolls_are_over(), true);
+        assert!(game.striking_rolls.striking_rolls_are_over());
         assert_eq!(game.rules.rolls_per_frame, 3);
     }
 
@@ -555,7 +818,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 8);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 1);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -566,7 +829,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 9);
         assert_eq!(game.current_frame, 2);
         assert_eq!(game.remaining_rolls_in_frame, 3);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -575,7 +838,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 0);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -583,7 +846,7 @@ mod three_rolls_per_frame_game {
         let rolls: Vec<u8> = vec![0; 29];
         let game = play_this_game(&rolls);
 
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -592,7 +855,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 300);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -601,7 +864,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 270);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -610,7 +873,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 30);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -622,7 +885,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.current_frame, 2);
         assert_eq!(game.remaining_rolls_in_frame, 3);
         assert_eq!(game.sparing, 1);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -633,8 +896,8 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 14);
         assert_eq!(game.sparing, 0);
         assert_eq!(game.current_frame, 3);
-        assert_eq!(game.frame_scores, vec![]);
-        assert_eq!(game.closed(), false);
+        assert!(game.frame_scores.is_empty());
+        assert!(!game.closed());
     }
 
     #[test]
@@ -645,7 +908,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 9);
         assert_eq!(game.current_frame, 2);
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -656,7 +919,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 10);
         assert_eq!(game.current_frame, 2);
         assert_eq!(first_slot(&game.striking_rolls), 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -667,7 +930,7 @@ mod three_rolls_per_frame_game {
         assert_eq!(game.score, 15);
         assert_eq!(game.current_frame, 3);
         assert_eq!(first_slot(&game.striking_rolls), 0);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -676,7 +939,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.current_frame, 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -685,7 +948,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 35);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -694,7 +957,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 32);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -703,7 +966,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 42);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -714,7 +977,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 156);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -726,7 +989,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 172);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -738,7 +1001,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 173);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -747,7 +1010,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 15);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -756,7 +1019,7 @@ mod three_rolls_per_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 36);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     fn play_this_game(rolls: &Vec<u8>) -> Game {
@@ -786,9 +1049,9 @@ mod twelve_frames_game {
         assert_eq!(game.total_rolls, 0);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 2);
-        assert_eq!(game.frame_scores, vec![]);
+        assert!(game.frame_scores.is_empty());
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.striking_rolls.striking_rolls_are_over(), true);
+        assert!(game.striking_rolls.striking_rolls_are_over());
         assert_eq!(game.rules.rolls_per_frame, 2);
         assert_eq!(game.rules.max_frames, 12);
     }
@@ -799,7 +1062,7 @@ mod twelve_frames_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 0);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -807,7 +1070,7 @@ mod twelve_frames_game {
         let rolls: Vec<u8> = vec![0; 23];
         let game = play_this_game(&rolls);
 
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -816,7 +1079,7 @@ mod twelve_frames_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 360);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -825,7 +1088,7 @@ mod twelve_frames_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 330);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -834,7 +1097,7 @@ mod twelve_frames_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 24);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -843,7 +1106,7 @@ mod twelve_frames_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 180);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     fn play_this_game(rolls: &Vec<u8>) -> Game {
@@ -875,9 +1138,9 @@ mod incremental_pins_game {
         assert_eq!(game.pins, 1);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 2);
-        assert_eq!(game.frame_scores, vec![]);
+        assert!(game.frame_scores.is_empty());
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.striking_rolls.striking_rolls_are_over(), true);
+        assert!(game.striking_rolls.striking_rolls_are_over());
         assert_eq!(game.rules.rolls_per_frame, 2);
         assert_eq!(game.rules.max_frames, 10);
         assert_eq!(game.rules.initial_pins, 1);
@@ -890,7 +1153,7 @@ mod incremental_pins_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 0);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -901,7 +1164,7 @@ mod incremental_pins_game {
         assert_eq!(game.score, 1);
         assert_eq!(game.current_frame, 2);
         assert_eq!(first_slot(&game.striking_rolls), 2);
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -910,7 +1173,7 @@ mod incremental_pins_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 191);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -919,7 +1182,7 @@ mod incremental_pins_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 161);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -928,7 +1191,7 @@ mod incremental_pins_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 24);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -937,7 +1200,7 @@ mod incremental_pins_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 86);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     fn play_this_game(rolls: &Vec<u8>) -> Game {
@@ -970,9 +1233,9 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         assert_eq!(game.total_rolls, 0);
         assert_eq!(game.current_frame, 1);
         assert_eq!(game.remaining_rolls_in_frame, 3);
-        assert_eq!(game.frame_scores, vec![]);
+        assert!(game.frame_scores.is_empty());
         assert_eq!(game.sparing, 0);
-        assert_eq!(game.striking_rolls.striking_rolls_are_over(), true);
+        assert!(game.striking_rolls.striking_rolls_are_over());
         assert_eq!(game.rules.rolls_per_frame, 3);
         assert_eq!(game.rules.max_frames, 12);
     }
@@ -983,7 +1246,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 0);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -991,7 +1254,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let rolls: Vec<u8> = vec![0; 35];
         let game = play_this_game(&rolls);
 
-        assert_eq!(game.closed(), false);
+        assert!(!game.closed());
     }
 
     #[test]
@@ -1000,7 +1263,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 360);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -1009,7 +1272,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 330);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -1018,7 +1281,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 36);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     #[test]
@@ -1030,7 +1293,7 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         let game = play_this_game(&rolls);
 
         assert_eq!(game.score, 165);
-        assert_eq!(game.closed(), true);
+        assert!(game.closed());
     }
 
     fn play_this_game(rolls: &Vec<u8>) -> Game {
@@ -1045,3 +1308,307 @@ mod twelve_frames_and_three_rolls_for_frame_game {
         game
     }
 }
+
+#[cfg(test)]
+mod low_ball_game {
+    use crate::game::*;
+
+    #[test]
+    fn gutter_balls_are_forbidden() {
+        let mut game = Game::new(low_ball_rules());
+
+        assert!(!game.roll(0));
+        assert!(game.roll(1));
+        assert_eq!(game.total_rolls, 1);
+    }
+
+    #[test]
+    fn the_best_low_ball_game() {
+        let rolls: Vec<u8> = vec![1; 20];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 20);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn forced_spare() {
+        let rolls: Vec<u8> = vec![9, 1, 1, 1];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 13);
+        assert!(!game.closed());
+    }
+
+    #[test]
+    fn spare_ends_a_three_ball_frame() {
+        let mut rules = low_ball_rules();
+        rules.rolls_per_frame = 3;
+        let mut game = Game::new(rules);
+
+        assert!(game.roll(9));
+        assert!(game.roll(1));
+        assert_eq!(game.current_frame(), 2);
+        assert!(game.roll(2));
+        assert_eq!(game.score, 14);
+    }
+
+    fn low_ball_rules() -> Rules {
+        let mut rules = Rules::new();
+        rules.variant = Variant::LowBall;
+        rules
+    }
+
+    fn play_this_game(rolls: &Vec<u8>) -> Game {
+        let mut game = Game::new(low_ball_rules());
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
+
+#[cfg(test)]
+mod spares_only_game {
+    use crate::game::*;
+
+    #[test]
+    fn strikes_have_no_bonus() {
+        let rolls: Vec<u8> = vec![10, 4, 4];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 18);
+        assert_eq!(game.current_frame, 3);
+    }
+
+    #[test]
+    fn spares_have_bonus() {
+        let rolls: Vec<u8> = vec![6, 4, 4, 4];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 22);
+    }
+
+    #[test]
+    fn the_perfect_game() {
+        let rolls: Vec<u8> = vec![10; 10];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 100);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn all_spares() {
+        let rolls: Vec<u8> = vec![5; 21];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 150);
+        assert!(game.closed());
+    }
+
+    fn play_this_game(rolls: &Vec<u8>) -> Game {
+        let mut rules = Rules::new();
+        rules.variant = Variant::SparesOnly;
+
+        let mut game = Game::new(rules);
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
+
+#[cfg(test)]
+mod three_six_nine_game {
+    use crate::game::*;
+
+    #[test]
+    fn initial_status_of_game() {
+        let mut rules = Rules::three_six_nine();
+        rules.auto_strike_frames = vec![1];
+
+        let game = Game::new(rules);
+
+        assert_eq!(game.score, 10);
+        assert_eq!(game.current_frame, 2);
+        assert_eq!(first_slot(&game.striking_rolls), 2);
+    }
+
+    #[test]
+    fn third_frame_is_credited() {
+        let rolls: Vec<u8> = vec![1, 1, 1, 1];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 14);
+        assert_eq!(game.current_frame, 4);
+        assert_eq!(game.total_rolls, 5);
+    }
+
+    #[test]
+    fn strike_bonus_on_credited_frames() {
+        let rolls: Vec<u8> = vec![1, 1, 1, 1, 3, 4];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 28);
+    }
+
+    #[test]
+    fn the_wrost_game() {
+        let rolls: Vec<u8> = vec![0; 14];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 30);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn the_perfect_game() {
+        let rolls: Vec<u8> = vec![10; 9];
+        let game = play_this_game(&rolls);
+
+        assert_eq!(game.score, 300);
+        assert!(game.closed());
+    }
+
+    fn play_this_game(rolls: &Vec<u8>) -> Game {
+        let mut game = Game::new(Rules::three_six_nine());
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
+
+#[cfg(test)]
+mod saved_game {
+    use crate::game::striking_bonuses::*;
+    use crate::game::*;
+
+    #[test]
+    fn resume_after_strikes() {
+        let mut game = play_this_game(&vec![10, 10]);
+
+        let mut resumed = Game::from_json(&game.to_json().unwrap()).unwrap();
+
+        assert_eq!(resumed.score, game.score);
+        assert_eq!(resumed.current_frame, 3);
+        assert_eq!(first_slot(&resumed.striking_rolls), 1);
+        assert_eq!(resumed.striking_rolls.get_striking_rolls_bonus(), 2);
+
+        game.roll(3);
+        resumed.roll(3);
+
+        assert_eq!(resumed.score, game.score);
+        assert_eq!(resumed.score, 39);
+    }
+
+    #[test]
+    fn resume_after_spare() {
+        let mut resumed = Game::from_json(&play_this_game(&vec![4, 6]).to_json().unwrap()).unwrap();
+
+        assert_eq!(resumed.sparing, 1);
+
+        resumed.roll(5);
+        assert_eq!(resumed.score, 20);
+    }
+
+    #[test]
+    fn resume_with_rules() {
+        let mut game = Game::new(Rules::three_six_nine());
+        game.roll(10);
+
+        let resumed = Game::from_json(&game.to_json().unwrap()).unwrap();
+
+        assert_eq!(resumed.rules().auto_strike_frames, vec![3, 6, 9]);
+        assert_eq!(resumed.frames(), game.frames());
+        assert_eq!(resumed.rolls(), vec![10]);
+    }
+
+    #[test]
+    fn resume_and_finish() {
+        let rolls: Vec<u8> = vec![10, 7, 3, 9, 0, 10, 0, 8, 8, 2, 0, 6, 10, 10, 10, 8, 1];
+        let mut resumed =
+            Game::from_json(&play_this_game(&rolls[..9].to_vec()).to_json().unwrap()).unwrap();
+        for pins in &rolls[9..] {
+            resumed.roll(*pins);
+        }
+
+        assert_eq!(resumed.score, play_this_game(&rolls).score);
+        assert!(resumed.closed());
+    }
+
+    #[test]
+    fn accept_valid_saves() {
+        for rolls in [vec![10; 12], [9, 1].repeat(10), vec![4, 6, 10, 0, 0]] {
+            let game = play_this_game(&rolls);
+
+            assert!(Game::from_json(&game.to_json().unwrap()).is_ok());
+        }
+        let mut game = Game::new(Rules::three_six_nine());
+        for pins in [10; 9] {
+            game.roll(pins);
+        }
+        assert!(Game::from_json(&game.to_json().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn refuse_state_not_matching_rolls() {
+        let mismatch = "invalid saved game: state does not match its rolls";
+
+        assert_eq!(refuse(|save| save["score"] = 65535.into()), mismatch);
+        assert_eq!(refuse(|save| save["total_rolls"] = 65535.into()), mismatch);
+        assert_eq!(refuse(|save| save["sparing"] = 1.into()), mismatch);
+        assert_eq!(refuse(|save| save["current_frame"] = 11.into()), mismatch);
+        assert_eq!(
+            refuse(|save| save["striking_rolls"]["rolls"] = serde_json::json!([])),
+            mismatch
+        );
+        assert_eq!(
+            refuse(|save| save["frames"] = serde_json::json!([])),
+            mismatch
+        );
+    }
+
+    #[test]
+    fn refuse_rolls_not_allowed() {
+        let nines = serde_json::json!(vec![9; 29]);
+
+        let error = refuse(|save| {
+            save["frame_scores"] = nines.clone();
+            save["frames"][2]["rolls"] = nines.clone();
+        });
+
+        assert_eq!(error, "invalid saved game: roll 4 refused");
+    }
+
+    #[test]
+    fn refuse_rules_out_of_range() {
+        let error = refuse(|save| save["rules"]["pins_increment_per_frame"] = 200.into());
+
+        assert_eq!(error, "invalid saved game: rules out of range");
+    }
+
+    #[test]
+    fn refuse_truncated_save() {
+        let json = play_this_game(&vec![10, 10]).to_json().unwrap();
+
+        assert!(Game::from_json(&json[..json.len() / 2]).is_err());
+    }
+
+    // Error loading the save of [10, 10] once edited
+    fn refuse(edit: impl Fn(&mut serde_json::Value)) -> String {
+        let mut save = serde_json::to_value(play_this_game(&vec![10, 10])).unwrap();
+        edit(&mut save);
+
+        Game::from_json(&save.to_string()).unwrap_err().to_string()
+    }
+
+    fn play_this_game(rolls: &Vec<u8>) -> Game {
+        let mut game = Game::new(Rules::new());
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
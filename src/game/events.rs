@@ -0,0 +1,149 @@
+use std::sync::mpsc::Sender;
+
+// Emitted by `Game::roll` once the roll has been applied
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    RollAccepted { frame: u8, pins: u8 },
+    Strike { frame: u8 },
+    Spare { frame: u8 },
+    // strike and spare bonus pins added to the score by the roll
+    BonusApplied { pins: u16 },
+    FrameCompleted { frame: u8 },
+    GameClosed { score: u16 },
+}
+
+pub trait GameListener: std::fmt::Debug {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+// Forward events to another thread, e.g. an overhead display
+impl GameListener for Sender<GameEvent> {
+    fn on_event(&mut self, event: &GameEvent) {
+        // a receiver gone away is not the game's business
+        let _ = self.send(event.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{channel, Receiver};
+
+    use crate::game::events::*;
+    use crate::game::rules::Rules;
+    use crate::game::Game;
+
+    #[test]
+    fn open_frame() {
+        let events = events_of(&[3, 4]);
+
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::RollAccepted { frame: 1, pins: 3 },
+                GameEvent::RollAccepted { frame: 1, pins: 4 },
+                GameEvent::FrameCompleted { frame: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn strike_and_bonus() {
+        let events = events_of(&[10, 3]);
+
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::RollAccepted { frame: 1, pins: 10 },
+                GameEvent::Strike { frame: 1 },
+                GameEvent::FrameCompleted { frame: 1 },
+                GameEvent::RollAccepted { frame: 2, pins: 3 },
+                GameEvent::BonusApplied { pins: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn spare() {
+        let events = events_of(&[0, 10]);
+
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::RollAccepted { frame: 1, pins: 0 },
+                GameEvent::RollAccepted { frame: 1, pins: 10 },
+                GameEvent::Spare { frame: 1 },
+                GameEvent::FrameCompleted { frame: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejected_roll_emits_nothing() {
+        let events = events_of(&[6, 6]);
+
+        assert_eq!(events.len(), 1);
+    }
+
+    #[test]
+    fn game_closed() {
+        let events = events_of(&[10; 12]);
+
+        assert_eq!(
+            events[events.len() - 3..],
+            [
+                GameEvent::BonusApplied { pins: 10 },
+                GameEvent::FrameCompleted { frame: 10 },
+                GameEvent::GameClosed { score: 300 },
+            ]
+        );
+    }
+
+    #[test]
+    fn credited_frames_are_notified() {
+        let (sender, receiver) = channel();
+        let mut game = Game::new(Rules::three_six_nine());
+        game.subscribe(Box::new(sender));
+
+        game.roll(1);
+        game.roll(1);
+        game.roll(1);
+
+        assert_eq!(
+            received(&receiver),
+            vec![
+                GameEvent::RollAccepted { frame: 1, pins: 1 },
+                GameEvent::RollAccepted { frame: 1, pins: 1 },
+                GameEvent::FrameCompleted { frame: 1 },
+                GameEvent::RollAccepted { frame: 2, pins: 1 },
+            ]
+        );
+
+        game.roll(1);
+
+        assert_eq!(
+            received(&receiver),
+            vec![
+                GameEvent::RollAccepted { frame: 2, pins: 1 },
+                GameEvent::FrameCompleted { frame: 2 },
+                GameEvent::RollAccepted { frame: 3, pins: 10 },
+                GameEvent::Strike { frame: 3 },
+                GameEvent::FrameCompleted { frame: 3 },
+            ]
+        );
+    }
+
+    fn events_of(rolls: &[u8]) -> Vec<GameEvent> {
+        let (sender, receiver) = channel();
+        let mut game = Game::new(Rules::new());
+        game.subscribe(Box::new(sender));
+
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        received(&receiver)
+    }
+
+    fn received(receiver: &Receiver<GameEvent>) -> Vec<GameEvent> {
+        receiver.try_iter().collect()
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::pin_set::PinSet;
+
+// How a roll is marked on a scorecard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mark {
+    Strike,
+    Spare,
+    Pins(u8),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    pub number: u8,
+    // pins in the rack
+    pub pins: u8,
+    pub rolls: Vec<u8>,
+    // bonus rolls of the last frame
+    pub fill_balls: Vec<u8>,
+    // pins knocked down by each roll, when known
+    pub knocked: Vec<Option<PinSet>>,
+    // credited as strike without rolling
+    pub credited: bool,
+}
+
+impl Frame {
+    pub fn new(number: u8, pins: u8) -> Frame {
+        Frame {
+            number,
+            pins,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_strike(&self) -> bool {
+        self.rolls.first() == Some(&self.pins)
+    }
+
+    pub fn is_spare(&self) -> bool {
+        !self.is_strike() && self.rolls.len() > 1 && self.rolls.iter().sum::<u8>() == self.pins
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.is_strike() && !self.is_spare()
+    }
+
+    // Pins standing after the first ball, when it has pin-level data
+    pub fn first_ball_leave(&self) -> Option<PinSet> {
+        match self.knocked.first() {
+            Some(Some(knocked)) => Some(PinSet::rack(self.pins).without(*knocked)),
+            _ => None,
+        }
+    }
+
+    // Pins standing for the next roll; a rack knocked down is reset
+    pub fn standing_pins(&self) -> u8 {
+        self.rolls
+            .iter()
+            .chain(self.fill_balls.iter())
+            .fold(self.pins, |standing, &pins| {
+                if pins >= standing {
+                    self.pins
+                } else {
+                    standing - pins
+                }
+            })
+    }
+
+    // Whether the next roll faces a full rack: a first ball or a fill ball
+    // after a strike or spare
+    pub fn fresh_rack(&self) -> bool {
+        !matches!(self.marks().last(), Some(Mark::Pins(_)))
+    }
+
+    // Rolls as scorecard marks, fill balls included
+    pub fn marks(&self) -> Vec<Mark> {
+        let mut standing = self.pins;
+        let mut fresh_rack = true;
+        let mut marks = vec![];
+
+        for &pins in self.rolls.iter().chain(self.fill_balls.iter()) {
+            if pins > 0 && pins >= standing {
+                marks.push(if fresh_rack {
+                    Mark::Strike
+                } else {
+                    Mark::Spare
+                });
+                standing = self.pins;
+                fresh_rack = true;
+            } else {
+                marks.push(Mark::Pins(pins));
+                standing -= pins;
+                fresh_rack = false;
+            }
+        }
+        marks
+    }
+
+    pub(crate) fn record(&mut self, pins: u8, knocked: Option<PinSet>, fill_ball: bool) {
+        if fill_ball {
+            self.fill_balls.push(pins);
+        } else {
+            self.rolls.push(pins);
+            self.knocked.push(knocked);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::frame::*;
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::game::Game;
+
+    #[test]
+    fn frames_of_a_game() {
+        let game = play_game(&[10, 7, 3, 4, 2], Rules::new());
+        let frames = game.frames();
+
+        assert_eq!(frames.len(), 4);
+        assert!(frames[0].is_strike());
+        assert!(frames[1].is_spare());
+        assert!(frames[2].is_open());
+        assert_eq!(frames[2].rolls, vec![4, 2]);
+        assert!(frames[3].rolls.is_empty());
+    }
+
+    #[test]
+    fn last_frame_fill_balls() {
+        let game = play_game(
+            &[10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 7, 3],
+            Rules::new(),
+        );
+        let last = &game.frames()[9];
+
+        assert_eq!(last.rolls, vec![10]);
+        assert_eq!(last.fill_balls, vec![7, 3]);
+        assert_eq!(last.marks(), vec![Mark::Strike, Mark::Pins(7), Mark::Spare]);
+    }
+
+    #[test]
+    fn marks() {
+        let game = play_game(&[0, 10, 9, 0], Rules::new());
+
+        assert_eq!(game.frames()[0].marks(), vec![Mark::Pins(0), Mark::Spare]);
+        assert_eq!(game.frames()[1].marks(), vec![Mark::Pins(9), Mark::Pins(0)]);
+    }
+
+    #[test]
+    fn fresh_rack() {
+        let game = play_game(&[0, 10, 9, 0], Rules::new());
+        let last = play_game(&[10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 7], Rules::new());
+
+        assert!(!game.frames()[1].fresh_rack());
+        assert!(game.frames()[2].fresh_rack());
+        assert!(!last.frames()[9].fresh_rack());
+    }
+
+    #[test]
+    fn credited_frames() {
+        let mut game = Game::new(Rules::three_six_nine());
+        for _ in 0..4 {
+            game.roll(1);
+        }
+
+        assert!(game.frames()[2].credited);
+        assert!(game.frames()[2].is_strike());
+        assert!(!game.frames()[1].credited);
+    }
+}
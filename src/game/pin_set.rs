@@ -0,0 +1,180 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use simple_error::SimpleError;
+
+static HEADPIN: u8 = 1;
+static TEN_PINS: u8 = 10;
+
+// Row and position in row of the ten pins, from the headpin
+static POSITIONS: [(i8, i8); 10] = [
+    (0, 0),
+    (1, -1),
+    (1, 1),
+    (2, -2),
+    (2, 0),
+    (2, 2),
+    (3, -3),
+    (3, -1),
+    (3, 1),
+    (3, 3),
+];
+
+// Pins numbered from 1 (headpin) as bits of a mask
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PinSet(u16);
+
+impl PinSet {
+    pub fn empty() -> PinSet {
+        PinSet(0)
+    }
+
+    // All the pins of a rack, from 1 to `pins`
+    pub fn rack(pins: u8) -> PinSet {
+        PinSet::from_pins(&(1..=pins).collect::<Vec<u8>>())
+    }
+
+    pub fn from_pins(pins: &[u8]) -> PinSet {
+        PinSet(
+            pins.iter()
+                .filter(|&&pin| (1..=16).contains(&pin))
+                .fold(0, |mask, pin| mask | 1 << (pin - 1)),
+        )
+    }
+
+    pub fn contains(&self, pin: u8) -> bool {
+        (1..=16).contains(&pin) && self.0 & 1 << (pin - 1) != 0
+    }
+
+    pub fn has_headpin(&self) -> bool {
+        self.contains(HEADPIN)
+    }
+
+    pub fn count(&self) -> u8 {
+        self.0.count_ones() as u8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn pins(&self) -> Vec<u8> {
+        (1..=16).filter(|&pin| self.contains(pin)).collect()
+    }
+
+    // Ten-pin leave with the headpin down and standing pins not touching
+    // each other, e.g. 7-10 or 5-6
+    pub fn is_split(&self) -> bool {
+        let pins = self.pins();
+        if self.has_headpin() || pins.len() < 2 || pins.iter().any(|&pin| pin > TEN_PINS) {
+            return false;
+        }
+
+        // pins touch when diagonally adjacent; flood from the first one
+        let mut reached = vec![pins[0]];
+        let mut index = 0;
+        while index < reached.len() {
+            let (row, position) = POSITIONS[reached[index] as usize - 1];
+            for &pin in &pins {
+                let (other_row, other_position) = POSITIONS[pin as usize - 1];
+                if (row - other_row).abs() == 1
+                    && (position - other_position).abs() == 1
+                    && !reached.contains(&pin)
+                {
+                    reached.push(pin);
+                }
+            }
+            index += 1;
+        }
+
+        reached.len() < pins.len()
+    }
+
+    // Pins in this set and not in `other`
+    pub fn without(&self, other: PinSet) -> PinSet {
+        PinSet(self.0 & !other.0)
+    }
+}
+
+// Leave notation, pins joined by dashes: "7-10"
+impl fmt::Display for PinSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pins: Vec<String> = self.pins().iter().map(|pin| pin.to_string()).collect();
+        write!(f, "{}", pins.join("-"))
+    }
+}
+
+impl FromStr for PinSet {
+    type Err = SimpleError;
+
+    fn from_str(s: &str) -> Result<PinSet, SimpleError> {
+        let mut pins = vec![];
+        for pin in s.trim().split('-') {
+            match pin.trim().parse::<u8>() {
+                Ok(pin) if (1..=16).contains(&pin) => pins.push(pin),
+                _ => return Err(simple_error!("invalid pin: {}", pin)),
+            }
+        }
+        Ok(PinSet::from_pins(&pins))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::pin_set::*;
+
+    #[test]
+    fn full_rack() {
+        let rack = PinSet::rack(10);
+
+        assert_eq!(rack.count(), 10);
+        assert!(rack.has_headpin());
+        assert!(rack.contains(10));
+        assert!(!rack.contains(11));
+    }
+
+    #[test]
+    fn leave_after_first_ball() {
+        let knocked = PinSet::from_pins(&[1, 2, 3, 4, 5, 6, 8, 9]);
+        let leave = PinSet::rack(10).without(knocked);
+
+        assert_eq!(leave.pins(), vec![7, 10]);
+        assert!(!leave.has_headpin());
+    }
+
+    #[test]
+    fn splits() {
+        assert!(PinSet::from_pins(&[7, 10]).is_split());
+        assert!(PinSet::from_pins(&[5, 6]).is_split());
+        assert!(PinSet::from_pins(&[2, 7]).is_split());
+        assert!(PinSet::from_pins(&[4, 6, 7, 10]).is_split());
+    }
+
+    #[test]
+    fn not_splits() {
+        assert!(!PinSet::from_pins(&[10]).is_split());
+        assert!(!PinSet::from_pins(&[3, 6, 10]).is_split());
+        assert!(!PinSet::from_pins(&[2, 4, 5, 8]).is_split());
+        assert!(!PinSet::from_pins(&[1, 7, 10]).is_split());
+    }
+
+    #[test]
+    fn leave_notation() {
+        let leave: PinSet = "7-10".parse().unwrap();
+
+        assert_eq!(leave, PinSet::from_pins(&[10, 7]));
+        assert_eq!(leave.to_string(), "7-10");
+        assert_eq!(
+            "7-x".parse::<PinSet>().unwrap_err().to_string(),
+            "invalid pin: x"
+        );
+    }
+
+    #[test]
+    fn invalid_pins_are_ignored() {
+        let pins = PinSet::from_pins(&[0, 3, 17]);
+
+        assert_eq!(pins.pins(), vec![3]);
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::pin_set::PinSet;
+
+static RED_HEADPIN_PRIZE: &str = "Red headpin strike";
+static HEADPIN_PRIZE: &str = "Headpin";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prize {
+    pub name: String,
+    pub frame: u8,
+}
+
+// What is known about an accepted roll; `knocked` is available only when
+// the roll comes with pin-level data
+#[derive(Debug)]
+pub struct RollContext {
+    pub frame: u8,
+    pub first_ball: bool,
+    pub pins: u8,
+    pub rack: u8,
+    pub knocked: Option<PinSet>,
+}
+
+impl RollContext {
+    pub fn is_strike(&self) -> bool {
+        self.first_ball && self.pins == self.rack
+    }
+}
+
+// Side-game rule attached to a game, evaluated after every accepted roll
+pub trait PrizeEvaluator: std::fmt::Debug {
+    fn evaluate(&mut self, roll: &RollContext) -> Option<Prize>;
+}
+
+// Frames where the headpin is red: a strike on the first ball wins
+#[derive(Debug, Default)]
+pub struct RedHeadpin {
+    pub frames: Vec<u8>,
+}
+
+impl PrizeEvaluator for RedHeadpin {
+    fn evaluate(&mut self, roll: &RollContext) -> Option<Prize> {
+        if self.frames.contains(&roll.frame) && roll.is_strike() {
+            return Some(Prize {
+                name: RED_HEADPIN_PRIZE.to_string(),
+                frame: roll.frame,
+            });
+        }
+        None
+    }
+}
+
+// Headpin game: every first ball knocking down the headpin wins
+#[derive(Debug, Default)]
+pub struct Headpin;
+
+impl PrizeEvaluator for Headpin {
+    fn evaluate(&mut self, roll: &RollContext) -> Option<Prize> {
+        match roll.knocked {
+            Some(knocked) if roll.first_ball && knocked.has_headpin() => Some(Prize {
+                name: HEADPIN_PRIZE.to_string(),
+                frame: roll.frame,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::prizes::*;
+    use crate::game::rules::Rules;
+    use crate::game::Game;
+
+    #[test]
+    fn red_headpin_strike_wins() {
+        let mut game = Game::new(Rules::new());
+        game.attach(Box::new(RedHeadpin { frames: vec![1, 3] }));
+
+        for pins in [10, 10, 10] {
+            game.roll(pins);
+        }
+
+        assert_eq!(
+            game.prizes(),
+            &[
+                Prize {
+                    name: "Red headpin strike".to_string(),
+                    frame: 1
+                },
+                Prize {
+                    name: "Red headpin strike".to_string(),
+                    frame: 3
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn red_headpin_needs_first_ball_strike() {
+        let mut game = Game::new(Rules::new());
+        game.attach(Box::new(RedHeadpin { frames: vec![1] }));
+
+        game.roll(0);
+        game.roll(10);
+
+        assert!(game.prizes().is_empty());
+    }
+
+    #[test]
+    fn headpin_on_first_ball() {
+        let mut game = Game::new(Rules::new());
+        game.attach(Box::new(Headpin));
+
+        game.roll_pin_set(PinSet::from_pins(&[1, 2, 3]));
+        game.roll_pin_set(PinSet::from_pins(&[4, 5]));
+        game.roll_pin_set(PinSet::from_pins(&[2, 4, 5]));
+        game.roll_pin_set(PinSet::from_pins(&[1]));
+
+        assert_eq!(game.score(), 9);
+        assert_eq!(
+            game.prizes(),
+            &[Prize {
+                name: "Headpin".to_string(),
+                frame: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn headpin_needs_pin_level_data() {
+        let mut game = Game::new(Rules::new());
+        game.attach(Box::new(Headpin));
+
+        game.roll(10);
+
+        assert!(game.prizes().is_empty());
+    }
+}
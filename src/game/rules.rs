@@ -1,14 +1,33 @@
+use serde::{Deserialize, Serialize};
+
 static DEFAULT_ROLLS_PER_FRAME: u8 = 2;
 static DEFAULT_MAX_FRAMES: u8 = 10;
 static DEFAULT_INITIAL_PINS: u8 = 10;
 static DEFAULT_PINS_INCREMENT_PER_FRAME: u8 = 0;
+static DEFAULT_FILL_BALLS: bool = true;
+static THREE_SIX_NINE_FRAMES: [u8; 3] = [3, 6, 9];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Variant {
+    #[default]
+    Standard,
+    // lowest score wins, gutter balls are forbidden
+    LowBall,
+    // strikes score no bonus, only spares do
+    SparesOnly,
+}
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Rules {
     pub rolls_per_frame: u8,
     pub max_frames: u8,
     pub initial_pins: u8,
     pub pins_increment_per_frame: u8,
+    // bonus rolls after a strike or spare in the last frame
+    pub fill_balls: bool,
+    pub variant: Variant,
+    // frames credited as strikes without rolling
+    pub auto_strike_frames: Vec<u8>,
 }
 
 impl Rules {
@@ -18,6 +37,23 @@ impl Rules {
             max_frames: DEFAULT_MAX_FRAMES,
             initial_pins: DEFAULT_INITIAL_PINS,
             pins_increment_per_frame: DEFAULT_PINS_INCREMENT_PER_FRAME,
+            fill_balls: DEFAULT_FILL_BALLS,
+            variant: Variant::Standard,
+            auto_strike_frames: vec![],
+        }
+    }
+
+    // Pins in the rack of the last frame
+    pub fn biggest_rack(&self) -> u16 {
+        self.initial_pins as u16
+            + self.pins_increment_per_frame as u16 * self.max_frames.saturating_sub(1) as u16
+    }
+
+    // 3-6-9 format: frames 3, 6 and 9 are strikes
+    pub fn three_six_nine() -> Rules {
+        Rules {
+            auto_strike_frames: THREE_SIX_NINE_FRAMES.to_vec(),
+            ..Rules::new()
         }
     }
 }
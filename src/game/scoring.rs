@@ -0,0 +1,412 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::game::frame::Frame;
+use crate::game::rules::{Rules, Variant};
+
+static STRIKE_BONUS_ROLLS: usize = 2;
+static SPARE_BONUS_ROLLS: usize = 1;
+
+// Rolls are referred to by their index in the scored sequence
+#[derive(Debug, PartialEq)]
+pub enum ScoreError {
+    TooManyPins { roll: usize, pins: u8, standing: u8 },
+    GutterBall { roll: usize },
+    RollAfterClose { roll: usize },
+    FillBallTooManyPins { roll: usize, pins: u8, standing: u8 },
+    // a fill ball rolled after a last frame without strike or spare
+    FillBallNotAllowed { roll: usize },
+}
+
+impl ScoreError {
+    // Index of the illegal roll
+    pub fn roll(&self) -> usize {
+        match self {
+            ScoreError::TooManyPins { roll, .. }
+            | ScoreError::GutterBall { roll }
+            | ScoreError::RollAfterClose { roll }
+            | ScoreError::FillBallTooManyPins { roll, .. }
+            | ScoreError::FillBallNotAllowed { roll } => *roll,
+        }
+    }
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScoreError::TooManyPins {
+                roll,
+                pins,
+                standing,
+            } => write!(
+                f,
+                "roll {}: {} pins with {} standing",
+                roll + 1,
+                pins,
+                standing
+            ),
+            ScoreError::GutterBall { roll } => {
+                write!(f, "roll {}: gutter balls are forbidden", roll + 1)
+            }
+            ScoreError::RollAfterClose { roll } => {
+                write!(f, "roll {}: game already closed", roll + 1)
+            }
+            ScoreError::FillBallTooManyPins {
+                roll,
+                pins,
+                standing,
+            } => write!(
+                f,
+                "roll {}: fill ball of {} pins with {} standing",
+                roll + 1,
+                pins,
+                standing
+            ),
+            ScoreError::FillBallNotAllowed { roll } => {
+                write!(
+                    f,
+                    "roll {}: no fill ball after an open last frame",
+                    roll + 1
+                )
+            }
+        }
+    }
+}
+
+impl Error for ScoreError {}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ScoreReport {
+    pub frames: Vec<Frame>,
+    // cumulative score at every frame, once its bonus is known
+    pub totals: Vec<Option<u16>>,
+    // score so far, as `Game::score`
+    pub score: u16,
+    pub complete: bool,
+}
+
+// Validate and score a complete or partial game; frames credited as
+// strikes are not part of `rolls`
+pub fn score_rolls(rolls: &[u8], rules: &Rules) -> Result<ScoreReport, ScoreError> {
+    let mut frames: Vec<Frame> = vec![];
+    let mut next = 0;
+    let mut complete = false;
+
+    for number in 1..=rules.max_frames {
+        let pins = rules.initial_pins + (number - 1) * rules.pins_increment_per_frame;
+        let last_frame = number == rules.max_frames;
+        let mut frame = Frame::new(number, pins);
+
+        if rules.auto_strike_frames.contains(&number) {
+            frame.credited = true;
+            frame.record(pins, None, false);
+        }
+
+        let mut standing = pins;
+        while !frame_is_over(&frame, rules) && next < rolls.len() {
+            let roll = check_roll(rolls, next, standing, rules)?;
+            frame.record(roll, None, false);
+            standing -= roll;
+            next += 1;
+        }
+
+        if !frame_is_over(&frame, rules) {
+            frames.push(frame);
+            break;
+        }
+
+        if last_frame {
+            let fill_balls = fill_balls(&frame, rules);
+            let mut standing = pins;
+            while frame.fill_balls.len() < fill_balls && next < rolls.len() {
+                let roll = check_roll(rolls, next, standing, rules).map_err(fill_ball_error)?;
+                frame.record(roll, None, true);
+                standing = if roll == standing {
+                    pins
+                } else {
+                    standing - roll
+                };
+                next += 1;
+            }
+            complete = frame.fill_balls.len() == fill_balls;
+            if complete && fill_balls == 0 && rules.fill_balls && next < rolls.len() {
+                return Err(ScoreError::FillBallNotAllowed { roll: next });
+            }
+        }
+
+        frames.push(frame);
+    }
+
+    if complete && next < rolls.len() {
+        return Err(ScoreError::RollAfterClose { roll: next });
+    }
+
+    Ok(ScoreReport {
+        totals: frame_totals(&frames, rules),
+        score: running_score(&frames, rules),
+        frames,
+        complete,
+    })
+}
+
+// Cumulative score at every frame, `None` from the first frame still
+// waiting for rolls or bonus
+pub fn frame_totals(frames: &[Frame], rules: &Rules) -> Vec<Option<u16>> {
+    let mut total = Some(0);
+    (0..frames.len())
+        .map(|index| {
+            let (score, known) = frame_score(frames, index, rules);
+            total = match (total, known) {
+                (Some(total), true) => Some(total + score),
+                _ => None,
+            };
+            total
+        })
+        .collect()
+}
+
+fn running_score(frames: &[Frame], rules: &Rules) -> u16 {
+    (0..frames.len())
+        .map(|index| frame_score(frames, index, rules).0)
+        .sum()
+}
+
+// Pins and bonus rolled so far for a frame, and whether that is final
+fn frame_score(frames: &[Frame], index: usize, rules: &Rules) -> (u16, bool) {
+    let frame = &frames[index];
+    let following: Vec<u16> = frame
+        .fill_balls
+        .iter()
+        .chain(
+            frames[index + 1..]
+                .iter()
+                .flat_map(|f| f.rolls.iter().chain(f.fill_balls.iter())),
+        )
+        .map(|&pins| pins as u16)
+        .collect();
+
+    let bonus_rolls = if frame.is_strike() && rules.variant != Variant::SparesOnly {
+        // a strike's bonus starts right after the strike
+        STRIKE_BONUS_ROLLS
+    } else if frame.is_spare() {
+        SPARE_BONUS_ROLLS
+    } else {
+        0
+    };
+
+    let pins: u16 = frame.rolls.iter().map(|&pins| pins as u16).sum();
+    let bonus: u16 = following.iter().take(bonus_rolls).sum();
+    let known = frame_is_over(frame, rules) && following.len() >= bonus_rolls;
+
+    (pins + bonus, known)
+}
+
+fn frame_is_over(frame: &Frame, rules: &Rules) -> bool {
+    let last_frame = frame.number == rules.max_frames;
+    frame.is_strike()
+        || frame.rolls.len() >= rules.rolls_per_frame as usize
+        || (!last_frame && frame.rolls.last() == Some(&frame.pins))
+        || (rules.variant == Variant::LowBall && frame.is_spare())
+}
+
+fn fill_balls(frame: &Frame, rules: &Rules) -> usize {
+    if !rules.fill_balls {
+        0
+    } else if frame.is_strike() && rules.variant != Variant::SparesOnly {
+        STRIKE_BONUS_ROLLS
+    } else if frame.is_spare() {
+        SPARE_BONUS_ROLLS
+    } else {
+        0
+    }
+}
+
+fn check_roll(rolls: &[u8], roll: usize, standing: u8, rules: &Rules) -> Result<u8, ScoreError> {
+    let pins = rolls[roll];
+    if rules.variant == Variant::LowBall && pins == 0 {
+        return Err(ScoreError::GutterBall { roll });
+    }
+    if pins > standing {
+        return Err(ScoreError::TooManyPins {
+            roll,
+            pins,
+            standing,
+        });
+    }
+    Ok(pins)
+}
+
+fn fill_ball_error(error: ScoreError) -> ScoreError {
+    match error {
+        ScoreError::TooManyPins {
+            roll,
+            pins,
+            standing,
+        } => ScoreError::FillBallTooManyPins {
+            roll,
+            pins,
+            standing,
+        },
+        error => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::scoring::*;
+    use crate::game::Game;
+    use crate::tournament::roll_off::RollOffFormat;
+
+    #[test]
+    fn complete_game() {
+        let rolls: Vec<u8> = vec![1, 4, 4, 5, 6, 4, 5, 5, 10, 0, 1, 7, 3, 6, 4, 10, 2, 8, 6];
+        let report = score_rolls(&rolls, &Rules::new()).unwrap();
+
+        assert_eq!(report.score, 133);
+        assert!(report.complete);
+        assert_eq!(
+            report.totals,
+            vec![5, 14, 29, 49, 60, 61, 77, 97, 117, 133]
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn partial_game() {
+        let report = score_rolls(&[10, 10, 3], &Rules::new()).unwrap();
+
+        assert_eq!(report.score, 39);
+        assert!(!report.complete);
+        assert_eq!(report.frames.len(), 3);
+        assert_eq!(report.totals, vec![Some(23), None, None]);
+    }
+
+    #[test]
+    fn no_rolls() {
+        let report = score_rolls(&[], &Rules::new()).unwrap();
+
+        assert_eq!(report.score, 0);
+        assert_eq!(report.frames.len(), 1);
+        assert_eq!(report.totals, vec![None]);
+    }
+
+    #[test]
+    fn too_many_pins() {
+        let error = score_rolls(&[3, 4, 6, 5], &Rules::new()).unwrap_err();
+
+        assert_eq!(
+            error,
+            ScoreError::TooManyPins {
+                roll: 3,
+                pins: 5,
+                standing: 4
+            }
+        );
+        assert_eq!(error.to_string(), "roll 4: 5 pins with 4 standing");
+    }
+
+    #[test]
+    fn fill_ball_faces_the_standing_pins() {
+        let mut rolls = vec![0; 18];
+        rolls.extend([10, 7, 4]);
+
+        let error = score_rolls(&rolls, &Rules::new()).unwrap_err();
+
+        assert_eq!(
+            error,
+            ScoreError::FillBallTooManyPins {
+                roll: 20,
+                pins: 4,
+                standing: 3
+            }
+        );
+    }
+
+    #[test]
+    fn roll_after_close() {
+        let error = score_rolls(&[10; 13], &Rules::new()).unwrap_err();
+
+        assert_eq!(error, ScoreError::RollAfterClose { roll: 12 });
+    }
+
+    #[test]
+    fn gutter_ball_in_low_ball() {
+        let mut rules = Rules::new();
+        rules.variant = Variant::LowBall;
+
+        let error = score_rolls(&[1, 0], &rules).unwrap_err();
+
+        assert_eq!(error, ScoreError::GutterBall { roll: 1 });
+    }
+
+    #[test]
+    fn same_score_as_game() {
+        let three_rolls = || {
+            let mut rules = Rules::new();
+            rules.rolls_per_frame = 3;
+            rules
+        };
+        let incremental = || {
+            let mut rules = Rules::new();
+            rules.initial_pins = 1;
+            rules.pins_increment_per_frame = 1;
+            rules
+        };
+        let low_ball = || {
+            let mut rules = Rules::new();
+            rules.rolls_per_frame = 3;
+            rules.variant = Variant::LowBall;
+            rules
+        };
+        let spares_only = || {
+            let mut rules = Rules::new();
+            rules.variant = Variant::SparesOnly;
+            rules
+        };
+        let cases: Vec<(Rules, Vec<u8>)> = vec![
+            (Rules::new(), vec![10; 12]),
+            (Rules::new(), vec![5; 21]),
+            (Rules::new(), vec![0, 10, 10, 5, 5, 3, 4, 10, 10, 10]),
+            (
+                Rules::new(),
+                vec![
+                    9, 1, 4, 4, 9, 0, 9, 0, 9, 1, 9, 0, 9, 0, 9, 0, 9, 0, 10, 8, 0,
+                ],
+            ),
+            (three_rolls(), vec![4, 6, 0, 3, 3, 3, 10, 2, 2, 2, 5, 5]),
+            (three_rolls(), vec![4, 3, 3, 4, 3, 3, 4, 3, 3]),
+            (incremental(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10]),
+            (
+                incremental(),
+                vec![1, 2, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 5, 4, 5, 5, 1],
+            ),
+            (Rules::three_six_nine(), vec![10; 9]),
+            (Rules::three_six_nine(), vec![1, 1, 1, 1, 3, 4, 5]),
+            (spares_only(), vec![10, 5, 5, 10, 4, 2]),
+            (low_ball(), vec![9, 1, 4, 3, 2, 10]),
+            (
+                low_ball(),
+                vec![
+                    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+                    1, 9, 1, 5,
+                ],
+            ),
+            (RollOffFormat::OneBall.rules(), vec![10]),
+        ];
+
+        for (rules, rolls) in cases {
+            let report = score_rolls(&rolls, &rules).unwrap();
+
+            let mut game = Game::new(rules);
+            for pins in &rolls {
+                assert!(game.roll(*pins));
+            }
+
+            assert_eq!(report.score, game.score(), "rolls {:?}", rolls);
+            assert_eq!(report.complete, game.closed(), "rolls {:?}", rolls);
+            assert_eq!(report.frames, game.frames(), "rolls {:?}", rolls);
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 static TOTAL_STRIKE_ROLLS: u8 = 2;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct StrikingBonus {
     rolls: Vec<u8>,
 }
@@ -0,0 +1,105 @@
+use crate::game::rules::Rules;
+use crate::game::scoring::{score_rolls, ScoreError};
+
+#[derive(Debug, PartialEq)]
+pub enum Validation {
+    Complete,
+    // `frame` is waiting for rolls or fill balls
+    Incomplete { frame: u8 },
+    // first illegal roll
+    Illegal(ScoreError),
+}
+
+pub fn validate(rolls: &[u8], rules: &Rules) -> Validation {
+    match score_rolls(rolls, rules) {
+        Ok(report) if report.complete => Validation::Complete,
+        Ok(report) => Validation::Incomplete {
+            frame: report.frames.last().map_or(1, |frame| frame.number),
+        },
+        Err(error) => Validation::Illegal(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::validation::*;
+    use crate::game::Game;
+
+    #[test]
+    fn complete_game() {
+        assert_eq!(validate(&[10; 12], &Rules::new()), Validation::Complete);
+    }
+
+    #[test]
+    fn incomplete_game() {
+        assert_eq!(
+            validate(&[10, 3], &Rules::new()),
+            Validation::Incomplete { frame: 2 }
+        );
+        assert_eq!(
+            validate(&[10; 11], &Rules::new()),
+            Validation::Incomplete { frame: 10 }
+        );
+    }
+
+    #[test]
+    fn overload() {
+        let validation = validate(&[10, 3, 8], &Rules::new());
+
+        assert_eq!(
+            validation,
+            Validation::Illegal(ScoreError::TooManyPins {
+                roll: 2,
+                pins: 8,
+                standing: 7
+            })
+        );
+    }
+
+    #[test]
+    fn roll_after_close() {
+        let mut rolls = vec![10; 12];
+        rolls.push(0);
+
+        match validate(&rolls, &Rules::new()) {
+            Validation::Illegal(error) => {
+                assert_eq!(error.roll(), 12);
+                assert_eq!(error.to_string(), "roll 13: game already closed");
+            }
+            validation => panic!("unexpected {:?}", validation),
+        }
+    }
+
+    #[test]
+    fn fill_ball_misuse() {
+        let mut rolls = vec![0; 20];
+        rolls.push(5);
+
+        assert_eq!(
+            validate(&rolls, &Rules::new()),
+            Validation::Illegal(ScoreError::FillBallNotAllowed { roll: 20 })
+        );
+
+        let mut rolls = vec![0; 18];
+        rolls.extend([4, 6, 10, 1]);
+
+        assert_eq!(
+            validate(&rolls, &Rules::new()),
+            Validation::Illegal(ScoreError::RollAfterClose { roll: 21 })
+        );
+    }
+
+    #[test]
+    fn rolls_of_a_game() {
+        let mut game = Game::new(Rules::three_six_nine());
+        for pins in [1, 2, 3, 4, 10, 10, 10, 10] {
+            game.roll(pins);
+        }
+
+        assert_eq!(game.rolls(), vec![1, 2, 3, 4, 10, 10, 10, 10]);
+        assert_eq!(
+            validate(&game.rolls(), game.rules()),
+            Validation::Incomplete { frame: 10 }
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::archive::fingerprint;
+use crate::game::rules::Rules;
+use crate::game::validation::{validate, Validation};
+use crate::game::Game;
+use crate::storage::file::{append_line, complete_lines};
+use crate::BoxResult;
+
+// Command applied to a game; corrections refer to rolls by index from 0
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Entry {
+    Roll { pins: u8 },
+    Correction { index: usize, pins: u8 },
+    Undo,
+}
+
+// First line of a journal file: fingerprint of the rules of the game
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    rules: u32,
+}
+
+// Every command accepted for a game, in order. The game is never stored:
+// it is rebuilt by replaying the commands, so a journal file left behind by
+// a crash brings the game back as it was.
+#[derive(Debug, Default)]
+pub struct Journal {
+    rules: Rules,
+    entries: Vec<Entry>,
+    // a header, then one JSON entry per line, appended before a command
+    // takes effect
+    file: Option<File>,
+}
+
+impl Journal {
+    pub fn new(rules: Rules) -> Journal {
+        Journal {
+            rules,
+            ..Default::default()
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P, rules: Rules) -> BoxResult<Journal> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+
+        let mut journal = Journal::new(rules);
+        let lines = complete_lines(&mut file)?;
+        match lines.split_first() {
+            // nothing to replay: a header alone is rewritten for the rules
+            Some((_, [])) => {
+                file.set_len(0)?;
+                journal.write_header(&mut file)?;
+            }
+            Some((header, entries)) => {
+                let header: Header = serde_json::from_str(header)?;
+                if header.rules != fingerprint(&journal.rules) {
+                    bail!("journal recorded with different rules");
+                }
+                for line in entries {
+                    let entry = serde_json::from_str(line)?;
+                    journal.rolls_after(entry)?;
+                    journal.entries.push(entry);
+                }
+            }
+            None => journal.write_header(&mut file)?,
+        }
+        journal.file = Some(file);
+        Ok(journal)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    // Rolls left standing by the commands so far
+    pub fn rolls(&self) -> Vec<u8> {
+        let mut rolls = vec![];
+        for entry in &self.entries {
+            apply(&mut rolls, entry);
+        }
+        rolls
+    }
+
+    pub fn game(&self) -> Game {
+        let mut game = Game::new(self.rules.clone());
+        for pins in self.rolls() {
+            game.roll(pins);
+        }
+        game
+    }
+
+    pub fn roll(&mut self, pins: u8) -> BoxResult<Game> {
+        self.record(Entry::Roll { pins })
+    }
+
+    pub fn correct(&mut self, index: usize, pins: u8) -> BoxResult<Game> {
+        self.record(Entry::Correction { index, pins })
+    }
+
+    pub fn undo(&mut self) -> BoxResult<Game> {
+        self.record(Entry::Undo)
+    }
+
+    // Starts a new game with the same rules
+    pub fn clear(&mut self) -> BoxResult<()> {
+        if let Some(mut file) = self.file.take() {
+            file.set_len(0)?;
+            self.write_header(&mut file)?;
+            self.file = Some(file);
+        }
+        self.entries.clear();
+        Ok(())
+    }
+
+    /*
+     *  Private functions
+     */
+
+    // Commands leaving an illegal game are refused and not journaled
+    fn record(&mut self, entry: Entry) -> BoxResult<Game> {
+        self.rolls_after(entry)?;
+        if let Some(file) = &mut self.file {
+            append_line(file, &serde_json::to_string(&entry)?)?;
+        }
+        self.entries.push(entry);
+        Ok(self.game())
+    }
+
+    fn write_header(&self, file: &mut File) -> BoxResult<()> {
+        let header = Header {
+            rules: fingerprint(&self.rules),
+        };
+        append_line(file, &serde_json::to_string(&header)?)
+    }
+
+    fn rolls_after(&self, entry: Entry) -> BoxResult<Vec<u8>> {
+        let mut rolls = self.rolls();
+        match entry {
+            Entry::Correction { index, .. } if index >= rolls.len() => {
+                bail!("no roll {} to correct", index + 1)
+            }
+            Entry::Undo if rolls.is_empty() => bail!("nothing to undo"),
+            _ => apply(&mut rolls, &entry),
+        }
+
+        if let Validation::Illegal(error) = validate(&rolls, &self.rules) {
+            bail!(error);
+        }
+        Ok(rolls)
+    }
+}
+
+fn apply(rolls: &mut Vec<u8>, entry: &Entry) {
+    match *entry {
+        Entry::Roll { pins } => rolls.push(pins),
+        Entry::Correction { index, pins } => rolls[index] = pins,
+        Entry::Undo => {
+            rolls.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::Write;
+
+    use crate::journal::*;
+    use crate::storage::file::temp_path;
+
+    #[test]
+    fn roll_and_undo() {
+        let mut journal = Journal::new(Rules::new());
+        journal.roll(10).unwrap();
+        journal.roll(7).unwrap();
+
+        let game = journal.undo().unwrap();
+
+        assert_eq!(game.rolls(), vec![10]);
+        assert_eq!(game.score(), 10);
+        assert_eq!(journal.entries().len(), 3);
+    }
+
+    #[test]
+    fn correction() {
+        let mut journal = Journal::new(Rules::new());
+        for pins in [7, 2, 5] {
+            journal.roll(pins).unwrap();
+        }
+
+        let game = journal.correct(1, 3).unwrap();
+
+        assert_eq!(game.rolls(), vec![7, 3, 5]);
+        assert_eq!(game.score(), 20);
+    }
+
+    #[test]
+    fn illegal_commands_are_refused() {
+        let mut journal = Journal::new(Rules::new());
+        journal.roll(7).unwrap();
+
+        assert_eq!(
+            journal.roll(4).unwrap_err().to_string(),
+            "roll 2: 4 pins with 3 standing"
+        );
+        assert_eq!(
+            journal.correct(1, 2).unwrap_err().to_string(),
+            "no roll 2 to correct"
+        );
+        assert_eq!(journal.entries().len(), 1);
+
+        journal.undo().unwrap();
+        assert_eq!(journal.undo().unwrap_err().to_string(), "nothing to undo");
+    }
+
+    #[test]
+    fn replay_after_restart() {
+        let path = temp_path("journal_restart");
+
+        let mut journal = Journal::open(&path, Rules::three_six_nine()).unwrap();
+        for pins in [10, 10, 4] {
+            journal.roll(pins).unwrap();
+        }
+        journal.undo().unwrap();
+        journal.correct(1, 2).unwrap();
+        let before = journal.game();
+        drop(journal);
+
+        let journal = Journal::open(&path, Rules::three_six_nine()).unwrap();
+        let after = journal.game();
+
+        assert_eq!(after.rolls(), vec![10, 2]);
+        assert_eq!(after.score(), before.score());
+        assert_eq!(after.frames(), before.frames());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn entry_cut_short() {
+        let path = temp_path("journal_cut_short");
+
+        let mut journal = Journal::open(&path, Rules::new()).unwrap();
+        journal.roll(9).unwrap();
+        drop(journal);
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"Roll\":{\"pi").unwrap();
+
+        let mut journal = Journal::open(&path, Rules::new()).unwrap();
+        journal.roll(1).unwrap();
+        drop(journal);
+
+        let journal = Journal::open(&path, Rules::new()).unwrap();
+        assert_eq!(journal.rolls(), vec![9, 1]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn clear_starts_new_game() {
+        let path = temp_path("journal_clear");
+
+        let mut journal = Journal::open(&path, Rules::new()).unwrap();
+        journal.roll(9).unwrap();
+        journal.clear().unwrap();
+        journal.roll(3).unwrap();
+        drop(journal);
+
+        let journal = Journal::open(&path, Rules::new()).unwrap();
+        assert_eq!(journal.rolls(), vec![3]);
+        assert!(Journal::open(&path, Rules::three_six_nine()).is_err());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn no_entries_with_other_rules() {
+        let path = temp_path("journal_no_entries");
+        drop(Journal::open(&path, Rules::new()).unwrap());
+
+        let mut journal = Journal::open(&path, Rules::three_six_nine()).unwrap();
+        journal.roll(10).unwrap();
+        drop(journal);
+
+        let journal = Journal::open(&path, Rules::three_six_nine()).unwrap();
+        assert_eq!(journal.rolls(), vec![10]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn different_rules() {
+        let path = temp_path("journal_rules");
+
+        let mut journal = Journal::open(&path, Rules::new()).unwrap();
+        for pins in [1, 1, 1, 1, 3, 4] {
+            journal.roll(pins).unwrap();
+        }
+        drop(journal);
+
+        let error = Journal::open(&path, Rules::three_six_nine()).unwrap_err();
+        assert_eq!(error.to_string(), "journal recorded with different rules");
+
+        let journal = Journal::open(&path, Rules::new()).unwrap();
+        assert_eq!(journal.game().score(), 11);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn header_cut_short() {
+        let path = temp_path("journal_header");
+        fs::write(&path, "{\"rul").unwrap();
+
+        let mut journal = Journal::open(&path, Rules::new()).unwrap();
+        journal.roll(4).unwrap();
+        drop(journal);
+
+        assert!(Journal::open(&path, Rules::three_six_nine()).is_err());
+        assert_eq!(Journal::open(&path, Rules::new()).unwrap().rolls(), vec![4]);
+        fs::remove_file(path).unwrap();
+    }
+}
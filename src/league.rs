@@ -0,0 +1,224 @@
+pub mod handicap;
+pub mod match_points;
+pub mod schedule;
+pub mod secretary;
+pub mod standings;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::rules::Rules;
+use crate::game::Game;
+
+static VACANCY_NAME: &str = "Vacancy";
+
+// Blind and vacancy series are synthetic: they count for team totals and
+// points but not for individual averages
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SeriesKind {
+    #[default]
+    Bowled,
+    Blind,
+    Vacancy,
+}
+
+// Games bowled by one bowler in a league session
+#[derive(Debug, Default)]
+pub struct Series {
+    pub bowler: String,
+    pub average: u16,
+    pub scores: Vec<u16>,
+    pub kind: SeriesKind,
+}
+
+impl Series {
+    pub fn new(bowler: &str, average: u16) -> Series {
+        Series {
+            bowler: bowler.to_string(),
+            average,
+            ..Default::default()
+        }
+    }
+
+    // Absent bowler: average minus a penalty for every game
+    pub fn blind(bowler: &str, average: u16, penalty: u16, games: usize) -> Series {
+        Series {
+            bowler: bowler.to_string(),
+            average,
+            scores: vec![average.saturating_sub(penalty); games],
+            kind: SeriesKind::Blind,
+        }
+    }
+
+    // Empty lineup slot: a fixed score for every game
+    pub fn vacancy(average: u16, score: u16, games: usize) -> Series {
+        Series {
+            bowler: VACANCY_NAME.to_string(),
+            average,
+            scores: vec![score; games],
+            kind: SeriesKind::Vacancy,
+        }
+    }
+
+    pub fn counts_for_average(&self) -> bool {
+        self.kind == SeriesKind::Bowled
+    }
+
+    pub fn add_game(&mut self, game: &Game) {
+        self.scores.push(game.score());
+    }
+
+    pub fn games(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn scratch(&self) -> u16 {
+        self.scores.iter().sum()
+    }
+}
+
+// Average of the games actually bowled, ignoring blind and vacancy series
+pub fn average(series: &[Series]) -> Option<u16> {
+    let bowled: Vec<u16> = series
+        .iter()
+        .filter(|s| s.counts_for_average())
+        .flat_map(|s| s.scores.iter().copied())
+        .collect();
+
+    if bowled.is_empty() {
+        return None;
+    }
+    Some((bowled.iter().map(|&score| score as u32).sum::<u32>() / bowled.len() as u32) as u16)
+}
+
+// Bowler on a league roster
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bowler {
+    pub name: String,
+    pub average: u16,
+}
+
+impl Bowler {
+    pub fn new(name: &str, average: u16) -> Bowler {
+        Bowler {
+            name: name.to_string(),
+            average,
+        }
+    }
+}
+
+// Teams bowling under the same rules, bowlers referred to by storage id
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct League {
+    pub name: String,
+    pub rules: Rules,
+    pub teams: Vec<String>,
+    pub bowlers: Vec<u64>,
+}
+
+impl League {
+    pub fn new(name: &str, rules: Rules) -> League {
+        League {
+            name: name.to_string(),
+            rules,
+            ..Default::default()
+        }
+    }
+}
+
+// A league night: storage ids of the bowler and game of every game bowled
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub league: u64,
+    pub week: u8,
+    pub games: Vec<(u64, u64)>,
+}
+
+impl Session {
+    pub fn new(league: u64, week: u8) -> Session {
+        Session {
+            league,
+            week,
+            ..Default::default()
+        }
+    }
+
+    pub fn add(&mut self, bowler: u64, game: u64) {
+        self.games.push((bowler, game));
+    }
+}
+
+// Bowlers' series for one team in a league session
+#[derive(Debug, Default)]
+pub struct Team {
+    pub name: String,
+    pub lineup: Vec<Series>,
+}
+
+impl Team {
+    pub fn new(name: &str) -> Team {
+        Team {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add(&mut self, series: Series) {
+        self.lineup.push(series);
+    }
+
+    pub fn games(&self) -> usize {
+        self.lineup.iter().map(|s| s.games()).max().unwrap_or(0)
+    }
+
+    pub fn scratch_game(&self, index: usize) -> u16 {
+        self.lineup.iter().filter_map(|s| s.scores.get(index)).sum()
+    }
+
+    pub fn scratch(&self) -> u16 {
+        self.lineup.iter().map(|s| s.scratch()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::*;
+
+    #[test]
+    fn blind_score() {
+        let blind = Series::blind("Alice", 160, 10, 3);
+
+        assert_eq!(blind.scores, vec![150, 150, 150]);
+        assert_eq!(blind.kind, SeriesKind::Blind);
+        assert!(!blind.counts_for_average());
+    }
+
+    #[test]
+    fn blind_penalty_above_average() {
+        let blind = Series::blind("Alice", 5, 10, 1);
+
+        assert_eq!(blind.scores, vec![0]);
+    }
+
+    #[test]
+    fn synthetic_series_count_for_team() {
+        let mut team = Team::new("Pinheads");
+        let mut bob = Series::new("Bob", 150);
+        bob.scores = vec![140, 160];
+        team.add(bob);
+        team.add(Series::blind("Alice", 160, 10, 2));
+        team.add(Series::vacancy(0, 120, 2));
+
+        assert_eq!(team.scratch_game(0), 410);
+        assert_eq!(team.scratch(), 840);
+    }
+
+    #[test]
+    fn synthetic_series_do_not_count_for_average() {
+        let mut bowled = Series::new("Alice", 160);
+        bowled.scores = vec![150, 171];
+        let blind = Series::blind("Alice", 160, 10, 3);
+
+        assert_eq!(average(&[bowled, blind]), Some(160));
+        assert_eq!(average(&[Series::vacancy(0, 120, 3)]), None);
+    }
+}
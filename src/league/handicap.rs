@@ -0,0 +1,111 @@
+use crate::league::{Series, Team};
+
+static DEFAULT_BASIS: u16 = 220;
+static DEFAULT_PERCENTAGE: u16 = 90;
+
+// Handicap = percentage of (basis - average), optionally capped
+#[derive(Debug, Default)]
+pub struct Handicap {
+    pub basis: u16,
+    pub percentage: u16,
+    pub cap: Option<u16>,
+}
+
+impl Handicap {
+    pub fn new() -> Handicap {
+        Handicap {
+            basis: DEFAULT_BASIS,
+            percentage: DEFAULT_PERCENTAGE,
+            cap: None,
+        }
+    }
+
+    // Handicap pins for a single game
+    pub fn for_average(&self, average: u16) -> u16 {
+        let pins =
+            (self.basis.saturating_sub(average) as u32 * self.percentage as u32 / 100) as u16;
+
+        match self.cap {
+            Some(cap) => pins.min(cap),
+            None => pins,
+        }
+    }
+
+    pub fn game(&self, series: &Series, index: usize) -> Option<u16> {
+        series
+            .scores
+            .get(index)
+            .map(|score| score + self.for_average(series.average))
+    }
+
+    pub fn series(&self, series: &Series) -> u16 {
+        series.scratch() + self.for_average(series.average) * series.games() as u16
+    }
+
+    pub fn team_game(&self, team: &Team, index: usize) -> u16 {
+        team.lineup.iter().filter_map(|s| self.game(s, index)).sum()
+    }
+
+    pub fn team_series(&self, team: &Team) -> u16 {
+        team.lineup.iter().map(|s| self.series(s)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::handicap::*;
+
+    #[test]
+    fn default_handicap() {
+        let handicap = Handicap::new();
+
+        assert_eq!(handicap.for_average(150), 63);
+        assert_eq!(handicap.for_average(219), 0);
+    }
+
+    #[test]
+    fn no_handicap_above_basis() {
+        let handicap = Handicap::new();
+
+        assert_eq!(handicap.for_average(220), 0);
+        assert_eq!(handicap.for_average(250), 0);
+    }
+
+    #[test]
+    fn capped_handicap() {
+        let mut handicap = Handicap::new();
+        handicap.cap = Some(50);
+
+        assert_eq!(handicap.for_average(100), 50);
+        assert_eq!(handicap.for_average(180), 36);
+    }
+
+    #[test]
+    fn series_with_handicap() {
+        let handicap = Handicap::new();
+        let mut series = Series::new("Alice", 160);
+        series.scores = vec![150, 170, 180];
+
+        assert_eq!(handicap.game(&series, 0), Some(204));
+        assert_eq!(handicap.game(&series, 3), None);
+        assert_eq!(handicap.series(&series), 662);
+    }
+
+    #[test]
+    fn team_with_handicap() {
+        let mut handicap = Handicap::new();
+        handicap.percentage = 100;
+
+        let mut team = Team::new("Pinheads");
+        let mut alice = Series::new("Alice", 200);
+        alice.scores = vec![210, 190];
+        let mut bob = Series::new("Bob", 150);
+        bob.scores = vec![140, 160];
+        team.add(alice);
+        team.add(bob);
+
+        assert_eq!(team.scratch_game(0), 350);
+        assert_eq!(handicap.team_game(&team, 0), 440);
+        assert_eq!(handicap.team_series(&team), 880);
+    }
+}
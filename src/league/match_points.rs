@@ -0,0 +1,184 @@
+use crate::league::handicap::Handicap;
+use crate::league::Team;
+
+static PETERSON_PINS_PER_BONUS_POINT: u16 = 50;
+
+// How points are awarded between two teams; ties split the points
+#[derive(Debug, Default)]
+pub struct PointSystem {
+    pub points_per_game: f32,
+    pub points_per_series: f32,
+    pub pins_per_bonus_point: u16,
+    pub with_handicap: bool,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct MatchPoints {
+    pub home: f32,
+    pub away: f32,
+}
+
+impl PointSystem {
+    // One point for each game won and one for the series total
+    pub fn point_per_game() -> PointSystem {
+        PointSystem {
+            points_per_game: 1.0,
+            points_per_series: 1.0,
+            pins_per_bonus_point: 0,
+            with_handicap: true,
+        }
+    }
+
+    // One point for each game won and one for every 50 pins knocked down
+    pub fn peterson() -> PointSystem {
+        PointSystem {
+            points_per_game: 1.0,
+            points_per_series: 0.0,
+            pins_per_bonus_point: PETERSON_PINS_PER_BONUS_POINT,
+            with_handicap: true,
+        }
+    }
+
+    pub fn award(&self, home: &Team, away: &Team, handicap: &Handicap) -> MatchPoints {
+        let mut points = MatchPoints::default();
+
+        for index in 0..home.games().max(away.games()) {
+            let (home_pins, away_pins) = (
+                self.team_game(home, index, handicap),
+                self.team_game(away, index, handicap),
+            );
+            Self::split(&mut points, home_pins, away_pins, self.points_per_game);
+        }
+
+        let (home_pins, away_pins) = (
+            self.team_series(home, handicap),
+            self.team_series(away, handicap),
+        );
+        Self::split(&mut points, home_pins, away_pins, self.points_per_series);
+
+        if let (Some(home_bonus), Some(away_bonus)) = (
+            home_pins.checked_div(self.pins_per_bonus_point),
+            away_pins.checked_div(self.pins_per_bonus_point),
+        ) {
+            points.home += home_bonus as f32;
+            points.away += away_bonus as f32;
+        }
+
+        points
+    }
+
+    fn team_game(&self, team: &Team, index: usize, handicap: &Handicap) -> u16 {
+        if self.with_handicap {
+            handicap.team_game(team, index)
+        } else {
+            team.scratch_game(index)
+        }
+    }
+
+    fn team_series(&self, team: &Team, handicap: &Handicap) -> u16 {
+        if self.with_handicap {
+            handicap.team_series(team)
+        } else {
+            team.scratch()
+        }
+    }
+
+    fn split(points: &mut MatchPoints, home_pins: u16, away_pins: u16, available: f32) {
+        match home_pins.cmp(&away_pins) {
+            std::cmp::Ordering::Greater => points.home += available,
+            std::cmp::Ordering::Less => points.away += available,
+            std::cmp::Ordering::Equal => {
+                points.home += available / 2.0;
+                points.away += available / 2.0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::handicap::Handicap;
+    use crate::league::match_points::*;
+    use crate::league::Series;
+
+    #[test]
+    fn point_per_game_scratch() {
+        let mut system = PointSystem::point_per_game();
+        system.with_handicap = false;
+        let home = team("Home", 180, &[200, 150, 190]);
+        let away = team("Away", 140, &[180, 170, 160]);
+
+        let points = system.award(&home, &away, &Handicap::new());
+
+        assert_eq!(
+            points,
+            MatchPoints {
+                home: 3.0,
+                away: 1.0
+            }
+        );
+    }
+
+    #[test]
+    fn point_per_game_with_handicap() {
+        let system = PointSystem::point_per_game();
+        let home = team("Home", 180, &[200, 150, 190]);
+        let away = team("Away", 140, &[180, 170, 160]);
+
+        // handicap: home 36, away 72
+        let points = system.award(&home, &away, &Handicap::new());
+
+        assert_eq!(
+            points,
+            MatchPoints {
+                home: 0.0,
+                away: 4.0
+            }
+        );
+    }
+
+    #[test]
+    fn ties_split_points() {
+        let mut system = PointSystem::point_per_game();
+        system.with_handicap = false;
+        let home = team("Home", 180, &[200, 150]);
+        let away = team("Away", 180, &[200, 160]);
+
+        let points = system.award(&home, &away, &Handicap::new());
+
+        assert_eq!(
+            points,
+            MatchPoints {
+                home: 0.5,
+                away: 2.5
+            }
+        );
+    }
+
+    #[test]
+    fn peterson_bonus_points() {
+        let mut system = PointSystem::peterson();
+        system.with_handicap = false;
+        let home = team("Home", 200, &[210, 190, 200]);
+        let away = team("Away", 200, &[180, 220, 150]);
+
+        let points = system.award(&home, &away, &Handicap::new());
+
+        // 600 pins -> 12 points, 550 pins -> 11 points
+        assert_eq!(
+            points,
+            MatchPoints {
+                home: 14.0,
+                away: 12.0
+            }
+        );
+    }
+
+    fn team(name: &str, average: u16, scores: &[u16]) -> Team {
+        let mut series = Series::new(name, average);
+        series.scores = scores.to_vec();
+        let mut team = Team::new(name);
+        team.add(series);
+        team
+    }
+}
@@ -0,0 +1,121 @@
+// Teams are referred to by their index in the league's team list
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Pairing {
+    pub home: usize,
+    pub away: usize,
+    pub lanes: (u8, u8),
+}
+
+#[derive(Debug, Default)]
+pub struct Week {
+    pub number: u8,
+    pub pairings: Vec<Pairing>,
+    pub bye: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct Schedule {
+    pub weeks: Vec<Week>,
+}
+
+impl Schedule {
+    // Every team meets every other team once, using the circle method;
+    // lane pairs rotate each week starting from `first_lane`
+    pub fn round_robin(teams: usize, first_lane: u8) -> Schedule {
+        let slots = teams + teams % 2;
+        let lane_pairs = slots / 2;
+        let mut circle: Vec<Option<usize>> = (0..teams).map(Some).collect();
+        if slots > teams {
+            circle.push(None);
+        }
+
+        let mut weeks = vec![];
+        for round in 0..slots.saturating_sub(1) {
+            let mut week = Week {
+                number: round as u8 + 1,
+                ..Default::default()
+            };
+
+            for slot in 0..lane_pairs {
+                match (circle[slot], circle[slots - 1 - slot]) {
+                    (Some(first), Some(second)) => {
+                        let (home, away) = if round % 2 == 0 {
+                            (first, second)
+                        } else {
+                            (second, first)
+                        };
+                        let pair = ((week.pairings.len() + round) % lane_pairs) as u8;
+                        week.pairings.push(Pairing {
+                            home,
+                            away,
+                            lanes: (first_lane + pair * 2, first_lane + pair * 2 + 1),
+                        });
+                    }
+                    (Some(team), None) | (None, Some(team)) => week.bye = Some(team),
+                    (None, None) => {}
+                }
+            }
+
+            weeks.push(week);
+            circle[1..].rotate_right(1);
+        }
+
+        Schedule { weeks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::schedule::*;
+
+    #[test]
+    fn every_team_meets_every_other_team() {
+        let schedule = Schedule::round_robin(6, 1);
+
+        assert_eq!(schedule.weeks.len(), 5);
+
+        let mut met = vec![vec![0; 6]; 6];
+        for week in &schedule.weeks {
+            assert_eq!(week.pairings.len(), 3);
+            assert_eq!(week.bye, None);
+            for pairing in &week.pairings {
+                met[pairing.home][pairing.away] += 1;
+                met[pairing.away][pairing.home] += 1;
+            }
+        }
+
+        for (team, opponents) in met.iter().enumerate() {
+            for (opponent, &times) in opponents.iter().enumerate() {
+                assert_eq!(times, if team == opponent { 0 } else { 1 });
+            }
+        }
+    }
+
+    #[test]
+    fn lane_pairs_rotate() {
+        let schedule = Schedule::round_robin(4, 1);
+
+        let lanes: Vec<Vec<(u8, u8)>> = schedule
+            .weeks
+            .iter()
+            .map(|w| w.pairings.iter().map(|p| p.lanes).collect())
+            .collect();
+
+        assert_eq!(lanes[0], vec![(1, 2), (3, 4)]);
+        assert_eq!(lanes[1], vec![(3, 4), (1, 2)]);
+        assert_eq!(lanes[2], vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn odd_teams_get_a_bye() {
+        let schedule = Schedule::round_robin(5, 1);
+
+        assert_eq!(schedule.weeks.len(), 5);
+
+        let mut byes: Vec<usize> = schedule.weeks.iter().filter_map(|w| w.bye).collect();
+        byes.sort();
+        assert_eq!(byes, vec![0, 1, 2, 3, 4]);
+        assert!(schedule.weeks.iter().all(|w| w.pairings.len() == 2));
+    }
+}
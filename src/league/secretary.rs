@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::rules::Rules;
+use crate::game::Game;
+use crate::league::Series;
+use crate::notation::formatter::format_game;
+use crate::notation::parser::parse_game;
+use crate::BoxResult;
+
+static GAMES_PER_RECORD: usize = 3;
+
+// One bowler's league night as exchanged with league secretary software:
+// scores of up to three games and, when known, their frames as notation
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub bowler: String,
+    pub week: u8,
+    pub game_1: Option<u16>,
+    pub game_2: Option<u16>,
+    pub game_3: Option<u16>,
+    pub frames_1: String,
+    pub frames_2: String,
+    pub frames_3: String,
+}
+
+impl Record {
+    pub fn from_games(bowler: &str, week: u8, games: &[Game]) -> BoxResult<Record> {
+        if games.len() > GAMES_PER_RECORD {
+            bail!("at most {} games per record", GAMES_PER_RECORD);
+        }
+
+        let score = |index: usize| games.get(index).map(|game| game.score());
+        let frames = |index: usize| games.get(index).map(format_game).unwrap_or_default();
+        Ok(Record {
+            bowler: bowler.to_string(),
+            week,
+            game_1: score(0),
+            game_2: score(1),
+            game_3: score(2),
+            frames_1: frames(0),
+            frames_2: frames(1),
+            frames_3: frames(2),
+        })
+    }
+
+    pub fn scores(&self) -> Vec<u16> {
+        [self.game_1, self.game_2, self.game_3]
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+
+    // Games rebuilt from the frames; a score column must agree with them
+    pub fn games(&self, rules: &Rules) -> BoxResult<Vec<Game>> {
+        let mut games = vec![];
+        let columns = [
+            (self.game_1, &self.frames_1),
+            (self.game_2, &self.frames_2),
+            (self.game_3, &self.frames_3),
+        ];
+
+        for (index, (score, frames)) in columns.iter().enumerate() {
+            if frames.is_empty() {
+                continue;
+            }
+
+            let game = parse_game(frames, rules.clone())?;
+            if let Some(score) = score {
+                if *score != game.score() {
+                    bail!(
+                        "{} week {} game {}: score {} does not match frames ({})",
+                        self.bowler,
+                        self.week,
+                        index + 1,
+                        score,
+                        game.score()
+                    );
+                }
+            }
+            games.push(game);
+        }
+        Ok(games)
+    }
+
+    pub fn series(&self, average: u16) -> Series {
+        Series {
+            scores: self.scores(),
+            ..Series::new(&self.bowler, average)
+        }
+    }
+}
+
+pub fn from_csv(text: &str) -> BoxResult<Vec<Record>> {
+    let mut reader = csv::Reader::from_reader(text.as_bytes());
+    let mut records = vec![];
+    for record in reader.deserialize() {
+        records.push(record?);
+    }
+    Ok(records)
+}
+
+// With a header row: bowler,week,game_1,game_2,game_3,frames_1,frames_2,frames_3
+pub fn to_csv(records: &[Record]) -> BoxResult<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for record in records {
+        writer.serialize(record)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::secretary::*;
+
+    static CSV: &str = "\
+bowler,week,game_1,game_2,game_3,frames_1,frames_2,frames_3
+Alice,1,300,90,,X X X X X X X X X XXX,9- 9- 9- 9- 9- 9- 9- 9- 9- 9-,
+\"Smith, Bob\",1,150,160,170,,,
+";
+
+    #[test]
+    fn read_records() {
+        let records = from_csv(CSV).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].scores(), vec![300, 90]);
+        assert_eq!(records[0].frames_3, "");
+        assert_eq!(records[1].bowler, "Smith, Bob");
+        assert_eq!(records[1].series(160).scratch(), 480);
+    }
+
+    #[test]
+    fn games_from_frames() {
+        let records = from_csv(CSV).unwrap();
+
+        let alice = records[0].games(&Rules::new()).unwrap();
+        let bob = records[1].games(&Rules::new()).unwrap();
+
+        assert_eq!(alice.len(), 2);
+        assert_eq!(alice[0].score(), 300);
+        assert!(bob.is_empty());
+    }
+
+    #[test]
+    fn score_not_matching_frames() {
+        let mut record = from_csv(CSV).unwrap().remove(0);
+        record.game_2 = Some(91);
+
+        assert_eq!(
+            record.games(&Rules::new()).unwrap_err().to_string(),
+            "Alice week 1 game 2: score 91 does not match frames (90)"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let games = from_csv(CSV).unwrap()[0].games(&Rules::new()).unwrap();
+        let records = vec![Record::from_games("Alice", 1, &games).unwrap()];
+
+        let text = to_csv(&records).unwrap();
+
+        assert_eq!(text.lines().nth(1), CSV.lines().nth(1));
+        assert_eq!(from_csv(&text).unwrap(), records);
+    }
+
+    #[test]
+    fn round_trip_with_credited_frames() {
+        let rules = Rules::three_six_nine();
+        let games = vec![
+            parse_game("11 11 34", rules.clone()).unwrap(),
+            parse_game("X X X X X X XXX", rules.clone()).unwrap(),
+        ];
+        let records = vec![Record::from_games("Alice", 2, &games).unwrap()];
+
+        let imported = from_csv(&to_csv(&records).unwrap()).unwrap()[0]
+            .games(&rules)
+            .unwrap();
+
+        assert_eq!(records[0].scores(), vec![28, 300]);
+        assert_eq!(imported[0].rolls(), games[0].rolls());
+        assert_eq!(imported[1].score(), 300);
+    }
+
+    #[test]
+    fn round_trip_with_racks_over_ten_pins() {
+        let mut rules = Rules::new();
+        rules.pins_increment_per_frame = 1;
+        let mut game = Game::new(rules.clone());
+        for pins in [3, 4, 10, 1, 12, 0, 11, 2] {
+            game.roll(pins);
+        }
+        let records = vec![Record::from_games("Alice", 3, &[game]).unwrap()];
+
+        let imported = from_csv(&to_csv(&records).unwrap()).unwrap()[0]
+            .games(&rules)
+            .unwrap();
+
+        assert_eq!(records[0].frames_1, "34 (10)/ X -(11) 2");
+        assert_eq!(imported[0].rolls(), vec![3, 4, 10, 1, 12, 0, 11, 2]);
+        assert_eq!(imported[0].score(), records[0].scores()[0]);
+    }
+
+    #[test]
+    fn too_many_games() {
+        let games: Vec<Game> = (0..4).map(|_| Game::new(Rules::new())).collect();
+
+        assert!(Record::from_games("Alice", 1, &games).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::league::match_points::MatchPoints;
+use crate::league::schedule::Pairing;
+
+// Accumulated match points, indexed like the schedule's teams
+#[derive(Debug, Default)]
+pub struct Standings {
+    pub points: Vec<f32>,
+}
+
+impl Standings {
+    pub fn new(teams: usize) -> Standings {
+        Standings {
+            points: vec![0.0; teams],
+        }
+    }
+
+    pub fn record(&mut self, pairing: &Pairing, points: &MatchPoints) {
+        self.points[pairing.home] += points.home;
+        self.points[pairing.away] += points.away;
+    }
+
+    // Team indexes from first to last place
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut teams: Vec<usize> = (0..self.points.len()).collect();
+        teams.sort_by(|a, b| self.points[*b].total_cmp(&self.points[*a]));
+        teams
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::league::match_points::MatchPoints;
+    use crate::league::schedule::Schedule;
+    use crate::league::standings::*;
+
+    #[test]
+    fn standings_from_schedule() {
+        let schedule = Schedule::round_robin(4, 1);
+        let mut standings = Standings::new(4);
+
+        for week in &schedule.weeks {
+            for pairing in &week.pairings {
+                // lower index always wins
+                let points = if pairing.home < pairing.away {
+                    MatchPoints {
+                        home: 4.0,
+                        away: 0.0,
+                    }
+                } else {
+                    MatchPoints {
+                        home: 0.0,
+                        away: 4.0,
+                    }
+                };
+                standings.record(pairing, &points);
+            }
+        }
+
+        assert_eq!(standings.points, vec![12.0, 8.0, 4.0, 0.0]);
+        assert_eq!(standings.ranking(), vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+pub mod achievements;
+pub mod archive;
+pub mod game;
+pub mod journal;
+pub mod league;
+pub mod notation;
+pub mod output;
+pub mod stats;
+pub mod storage;
+pub mod tournament;
+
+#[macro_use]
+extern crate simple_error;
+
+// Types
+pub type BoxResult<T> = Result<T, Box<dyn Error>>;
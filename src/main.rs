@@ -1,35 +1,53 @@
-use std::error::Error;
 use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use regex::Regex;
 
-mod game;
-use game::*;
-
-use game::rules::Rules;
+use scoring_bowling::game::rules::Rules;
+use scoring_bowling::journal::Journal;
+use scoring_bowling::output::scorecard::scorecard;
+use scoring_bowling::BoxResult;
 
 #[macro_use]
 extern crate simple_error;
 
-// Types
-type BoxResult<T> = Result<T, Box<dyn Error>>;
+// Commands of the game in progress, replayed when the REPL restarts
+static JOURNAL_PATH: &str = "scoring_bowling.journal";
 
 #[derive(Debug, PartialEq)]
 enum Command {
     Roll { pins: u8 },
+    Correct { roll: usize, pins: u8 },
+    Undo,
     Score,
     Exit,
 }
 
 fn main() {
+    let rules = match std::env::args().nth(1).as_deref() {
+        Some("369") => Rules::three_six_nine(),
+        _ => Rules::new(),
+    };
+
     println!("SCORING BOWLING");
+    if !rules.auto_strike_frames.is_empty() {
+        println!("  3-6-9 format: frames 3, 6 and 9 are credited as strikes");
+    }
     println!("  Commands:");
     println!("    roll N - N pins rolled (0 to 10)");
+    println!("    correct R N - roll number R knocked down N pins");
+    println!("    undo - cancel the last roll");
     println!("    score - print score of current game");
     println!("    exit - exit from game");
     println!();
 
-    let mut game = Game::new(Rules::new());
+    let mut journal = open_journal(rules);
+    let mut game = journal.game();
+    if !journal.entries().is_empty() {
+        println!("Game resumed after {} rolls", game.rolls().len());
+        println!("{}", scorecard(&game));
+    }
 
     while !game.closed() {
         print!("Command: ");
@@ -46,15 +64,71 @@ fn main() {
             }
             Ok(Command::Roll { pins }) => {
                 println!("Rolled {} pins", pins);
-                if !game.roll(pins) {
-                    println!("Invalid pins");
+                let frame = game.current_frame();
+                match journal.roll(pins) {
+                    Ok(rolled) => game = rolled,
+                    Err(error) => println!("Invalid pins: {}", error),
                 }
+                // frames skipped by the game have been credited as strikes
+                for credited in frame + 1..game.current_frame() {
+                    println!("Frame {} credited as strike", credited);
+                }
+                println!("{}", scorecard(&game));
+            }
+            Ok(Command::Correct { roll, pins }) => {
+                match journal.correct(roll.saturating_sub(1), pins) {
+                    Ok(corrected) => game = corrected,
+                    Err(error) => println!("Invalid correction: {}", error),
+                }
+                println!("{}", scorecard(&game));
+            }
+            Ok(Command::Undo) => {
+                match journal.undo() {
+                    Ok(undone) => game = undone,
+                    Err(error) => println!("Error: {}", error),
+                }
+                println!("{}", scorecard(&game));
             }
             Err(err) => println!("Error: {}", err),
         }
     }
 
     println!("Game over - final score: {}", game.score());
+    if let Err(error) = journal.clear() {
+        println!("Error: {}", error);
+    }
+}
+
+// A finished game starts a new one. A journal that cannot be replayed, e.g.
+// recorded with other rules, is renamed aside and never deleted.
+fn open_journal(rules: Rules) -> Journal {
+    match Journal::open(JOURNAL_PATH, rules.clone()) {
+        Ok(mut journal) => {
+            if journal.game().closed() {
+                let _ = journal.clear();
+            }
+            journal
+        }
+        Err(error) => {
+            println!("Game not resumed: {}", error);
+            let seconds = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            let aside = (0..)
+                .map(|copy| format!("{}.{}-{}", JOURNAL_PATH, seconds, copy))
+                .find(|aside| !Path::new(aside).exists())
+                .unwrap_or_default();
+            if let Err(error) = std::fs::rename(JOURNAL_PATH, &aside) {
+                println!(
+                    "Journal kept as is, this game will not be recorded: {}",
+                    error
+                );
+                return Journal::new(rules);
+            }
+            println!("Journal moved to {}", aside);
+            Journal::open(JOURNAL_PATH, rules.clone()).unwrap_or_else(|_| Journal::new(rules))
+        }
+    }
 }
 
 // Get command from console
@@ -69,9 +143,19 @@ fn read_command() -> String {
 // From user string to command
 fn translate_command(command: &str) -> BoxResult<Command> {
     let roll_re: Regex = Regex::new("roll\\s+(?P<pins>(10|[0-9]))\\s*$").unwrap();
+    let correct_re: Regex =
+        Regex::new("correct\\s+(?P<roll>[1-9][0-9]?)\\s+(?P<pins>(10|[0-9]))\\s*$").unwrap();
     let normalized_command = command.trim().to_lowercase();
 
-    if normalized_command.starts_with("roll") {
+    if normalized_command.starts_with("correct") {
+        match correct_re.captures(&normalized_command) {
+            Some(c) => Ok(Command::Correct {
+                roll: c["roll"].parse()?,
+                pins: c["pins"].parse()?,
+            }),
+            _ => bail!("invalid correction"),
+        }
+    } else if normalized_command.starts_with("roll") {
         match roll_re.captures(&normalized_command) {
             Some(c) => {
                 let p: u8 = c["pins"].trim().parse()?;
@@ -81,6 +165,7 @@ fn translate_command(command: &str) -> BoxResult<Command> {
         }
     } else {
         match normalized_command.as_str() {
+            "undo" => Ok(Command::Undo),
             "score" => Ok(Command::Score),
             "exit" => Ok(Command::Exit),
             _ => bail!("invalid command"),
@@ -163,4 +248,26 @@ mod tests {
         let command = translate_command("roll ab");
         assert_eq!(command.unwrap_err().to_string(), "invalid pins");
     }
+
+    // Test corrections
+
+    #[test]
+    fn correct_undo() {
+        let command = translate_command(" Undo ");
+        assert_eq!(command.unwrap(), Command::Undo);
+    }
+
+    #[test]
+    fn correct_correction() {
+        let command = translate_command("correct 12 7");
+        assert_eq!(command.unwrap(), Command::Correct { roll: 12, pins: 7 });
+    }
+
+    #[test]
+    fn incorrect_correction() {
+        for command in ["correct", "correct 3", "correct 0 7", "correct 3 11"] {
+            let command = translate_command(command);
+            assert_eq!(command.unwrap_err().to_string(), "invalid correction");
+        }
+    }
 }
@@ -0,0 +1,104 @@
+use crate::game::frame::{Frame, Mark};
+use crate::game::Game;
+
+pub fn format_mark(mark: &Mark) -> String {
+    match mark {
+        Mark::Strike => "X".to_string(),
+        Mark::Spare => "/".to_string(),
+        Mark::Pins(0) => "-".to_string(),
+        // racks over ten pins: kept apart from the next mark
+        Mark::Pins(pins) if *pins >= 10 => format!("({})", pins),
+        Mark::Pins(pins) => pins.to_string(),
+    }
+}
+
+// Marks of a frame, e.g. "X", "7/" or "X81"
+pub fn format_frame(frame: &Frame) -> String {
+    frame.marks().iter().map(format_mark).collect()
+}
+
+// Frames separated by spaces, e.g. "X 7/ 9- X81"; frames credited as
+// strikes are not written, as the parser expects
+pub fn format_game(game: &Game) -> String {
+    game.frames()
+        .iter()
+        .filter(|frame| !frame.credited)
+        .map(format_frame)
+        .filter(|frame| !frame.is_empty())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::notation::formatter::*;
+    use crate::notation::parser::parse_game;
+
+    #[test]
+    fn round_trip() {
+        for notation in [
+            "X 7/ 9- X -8 8/ -6 X X X81",
+            "X X X X X X X X X XXX",
+            "-/ -/ -/ -/ -/ -/ -/ -/ -/ -/-",
+            "9- 9- 9- 9- 9- 9- 9- 9- 9- 9-",
+            "X 7/ 9",
+        ] {
+            let game = parse_game(notation, Rules::new()).unwrap();
+
+            assert_eq!(format_game(&game), notation);
+        }
+
+        for notation in ["11 11 34", "X X X X X X XXX", "-- -- -- -- -- -- --"] {
+            let game = parse_game(notation, Rules::three_six_nine()).unwrap();
+
+            assert_eq!(format_game(&game), notation);
+        }
+    }
+
+    #[test]
+    fn credited_frames_not_written() {
+        let game = parse_game("11 11 34", Rules::three_six_nine()).unwrap();
+
+        assert_eq!(game.score(), 28);
+        assert_eq!(
+            parse_game(&format_game(&game), Rules::three_six_nine())
+                .unwrap()
+                .score(),
+            28
+        );
+    }
+
+    #[test]
+    fn no_rolls() {
+        assert_eq!(format_game(&Game::new(Rules::new())), "");
+    }
+
+    #[test]
+    fn incremental_pins() {
+        let mut rules = Rules::new();
+        rules.initial_pins = 1;
+        rules.pins_increment_per_frame = 1;
+        let mut game = Game::new(rules);
+        for pins in [1, 0, 2, 3] {
+            game.roll(pins);
+        }
+
+        assert_eq!(format_game(&game), "X -/ X");
+    }
+
+    #[test]
+    fn round_trip_with_racks_over_ten_pins() {
+        let mut rules = Rules::new();
+        rules.pins_increment_per_frame = 1;
+        let mut game = Game::new(rules.clone());
+        for pins in [3, 4, 10, 1, 12, 0, 11, 2] {
+            game.roll(pins);
+        }
+
+        let notation = format_game(&game);
+
+        assert_eq!(notation, "34 (10)/ X -(11) 2");
+        assert_eq!(parse_game(&notation, rules).unwrap().rolls(), game.rolls());
+    }
+}
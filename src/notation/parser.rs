@@ -0,0 +1,258 @@
+use crate::game::rules::Rules;
+use crate::game::validation::{validate, Validation};
+use crate::game::Game;
+use crate::BoxResult;
+
+// Rolls read from scorecard notation: X strike, / spare, - miss, F foul,
+// digits for pins, counts of 10 or more in brackets as in "(12)" for racks
+// over ten pins, S before a first ball leaving a split. Spaces and `|`
+// between frames are optional. Frames credited as strikes are not written.
+#[derive(Debug, Default, PartialEq)]
+pub struct Notation {
+    pub rolls: Vec<u8>,
+    // indexes of the rolls that were fouls
+    pub fouls: Vec<usize>,
+    // indexes of the first balls that left a split
+    pub splits: Vec<usize>,
+}
+
+impl Notation {
+    pub fn parse(text: &str, rules: &Rules) -> BoxResult<Notation> {
+        let mut notation = Notation::default();
+        // a game keeps track of the pins standing for strikes and spares
+        let mut game = Game::new(rules.clone());
+        // position of a split marker waiting for its first ball
+        let mut split = None;
+
+        let mut marks = text.chars().enumerate();
+        while let Some((position, mark)) = marks.next() {
+            let fresh_rack = game.frames().last().is_none_or(|frame| frame.fresh_rack());
+            let pins = match mark {
+                ' ' | '|' => continue,
+                'S' | 's' => {
+                    if split.is_some() || !fresh_rack {
+                        bail!(
+                            "split mark not before a first ball at position {}",
+                            position + 1
+                        );
+                    }
+                    split = Some(position);
+                    continue;
+                }
+                'X' | 'x' => {
+                    if split.is_some() || !fresh_rack {
+                        bail!("strike not on a full rack at position {}", position + 1);
+                    }
+                    game.standing_pins()
+                }
+                '/' => {
+                    if fresh_rack {
+                        bail!("spare on a first ball at position {}", position + 1);
+                    }
+                    game.standing_pins()
+                }
+                '-' => 0,
+                'F' | 'f' => {
+                    notation.fouls.push(notation.rolls.len());
+                    0
+                }
+                '0'..='9' => mark as u8 - b'0',
+                '(' => {
+                    let mut count = String::new();
+                    let mut closed = false;
+                    for (_, digit) in marks.by_ref() {
+                        if digit == ')' {
+                            closed = true;
+                            break;
+                        }
+                        count.push(digit);
+                    }
+                    match count.parse() {
+                        Ok(pins) if closed => pins,
+                        _ => bail!("invalid pin count at position {}", position + 1),
+                    }
+                }
+                _ => bail!("invalid mark '{}' at position {}", mark, position + 1),
+            };
+
+            if game.closed() {
+                bail!(
+                    "rolls after the end of the game at position {}",
+                    position + 1
+                );
+            }
+            if !game.roll(pins) {
+                bail!("invalid roll '{}' at position {}", mark, position + 1);
+            }
+
+            if split.take().is_some() {
+                notation.splits.push(notation.rolls.len());
+            }
+            notation.rolls.push(pins);
+        }
+
+        if let Some(position) = split {
+            bail!("split mark without a roll at position {}", position + 1);
+        }
+
+        if let Validation::Illegal(error) = validate(&notation.rolls, rules) {
+            bail!(error);
+        }
+
+        Ok(notation)
+    }
+
+    pub fn game(&self, rules: Rules) -> Game {
+        let mut game = Game::new(rules);
+        for pins in &self.rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
+
+pub fn parse_game(text: &str, rules: Rules) -> BoxResult<Game> {
+    Ok(Notation::parse(text, &rules)?.game(rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::notation::parser::*;
+
+    #[test]
+    fn complete_game() {
+        let game = parse_game("X 7/ 9- X -8 8/ -6 X X X81", Rules::new()).unwrap();
+
+        assert_eq!(
+            game.rolls(),
+            vec![10, 7, 3, 9, 0, 10, 0, 8, 8, 2, 0, 6, 10, 10, 10, 8, 1]
+        );
+        assert_eq!(game.score(), 167);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn perfect_game_without_spaces() {
+        let game = parse_game("XXXXXXXXXXXX", Rules::new()).unwrap();
+
+        assert_eq!(game.score(), 300);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn fouls_and_splits() {
+        let notation = Notation::parse("F/ | S8/ | S71 | 9F", &Rules::new()).unwrap();
+
+        assert_eq!(notation.rolls, vec![0, 10, 8, 2, 7, 1, 9, 0]);
+        assert_eq!(notation.fouls, vec![0, 7]);
+        assert_eq!(notation.splits, vec![2, 4]);
+    }
+
+    #[test]
+    fn spare_in_fill_balls() {
+        let game = parse_game("------------------X7/", Rules::new()).unwrap();
+
+        assert_eq!(game.score(), 20);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn partial_game() {
+        let game = parse_game("X 7/ 9", Rules::new()).unwrap();
+
+        assert_eq!(game.score(), 48);
+        assert!(!game.closed());
+    }
+
+    #[test]
+    fn invalid_mark() {
+        let error = Notation::parse("X 7/ 9?", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid mark '?' at position 7");
+    }
+
+    #[test]
+    fn pin_counts_in_brackets() {
+        let mut rules = Rules::new();
+        rules.pins_increment_per_frame = 1;
+
+        let notation = Notation::parse("34 (10)/ X (11)-", &rules).unwrap();
+
+        assert_eq!(notation.rolls, vec![3, 4, 10, 1, 12, 11, 0]);
+        assert_eq!(
+            Notation::parse("34 (10", &rules).unwrap_err().to_string(),
+            "invalid pin count at position 4"
+        );
+        assert_eq!(
+            Notation::parse("34 (a)", &rules).unwrap_err().to_string(),
+            "invalid pin count at position 4"
+        );
+    }
+
+    #[test]
+    fn strike_after_first_ball() {
+        let error = Notation::parse("X 7X", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "strike not on a full rack at position 4");
+    }
+
+    #[test]
+    fn spare_on_first_ball() {
+        let error = Notation::parse("/ 7X", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "spare on a first ball at position 1");
+    }
+
+    #[test]
+    fn split_before_second_ball() {
+        let error = Notation::parse("9S/", &Rules::new()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "split mark not before a first ball at position 2"
+        );
+    }
+
+    #[test]
+    fn split_without_roll() {
+        let error = Notation::parse("S81 S", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "split mark without a roll at position 5");
+    }
+
+    #[test]
+    fn marks_in_fill_balls() {
+        let error = Notation::parse("------------------X7X", &Rules::new()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "strike not on a full rack at position 21"
+        );
+        assert!(Notation::parse("------------------XS7/", &Rules::new()).is_ok());
+        assert!(Notation::parse("------------------7/X", &Rules::new()).is_ok());
+    }
+
+    #[test]
+    fn too_many_pins() {
+        let error = Notation::parse("X 78", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid roll '8' at position 4");
+    }
+
+    #[test]
+    fn rolls_after_the_end() {
+        let error = Notation::parse("XXXXXXXXXXXXX", &Rules::new()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "rolls after the end of the game at position 13"
+        );
+    }
+
+    #[test]
+    fn fill_ball_over_standing_pins() {
+        let error = Notation::parse("------------------X78", &Rules::new()).unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid roll '8' at position 21");
+    }
+}
@@ -0,0 +1,91 @@
+pub mod html;
+pub mod scoreboard;
+pub mod scorecard;
+pub mod svg;
+
+use crate::game::Game;
+use crate::league::handicap::Handicap;
+use crate::league::Series;
+
+// A bowler's games as printed on a scorecard export; totals come from the
+// league series and handicap, as on the recap sheets
+#[derive(Debug)]
+pub struct Card<'a> {
+    pub series: Series,
+    pub games: &'a [Game],
+    pub handicap: &'a Handicap,
+}
+
+impl<'a> Card<'a> {
+    pub fn new(bowler: &str, average: u16, games: &'a [Game], handicap: &'a Handicap) -> Card<'a> {
+        let mut series = Series::new(bowler, average);
+        for game in games {
+            series.add_game(game);
+        }
+        Card {
+            series,
+            games,
+            handicap,
+        }
+    }
+
+    // Handicap pins of every game
+    pub fn game_handicap(&self) -> u16 {
+        self.handicap.for_average(self.series.average)
+    }
+
+    pub fn game_total(&self, index: usize) -> u16 {
+        self.handicap.game(&self.series, index).unwrap_or_default()
+    }
+
+    pub fn scratch(&self) -> u16 {
+        self.series.scratch()
+    }
+
+    pub fn series_handicap(&self) -> u16 {
+        self.series_total() - self.scratch()
+    }
+
+    pub fn series_total(&self) -> u16 {
+        self.handicap.series(&self.series)
+    }
+}
+
+// Escape text for SVG and HTML documents
+pub(crate) fn escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::notation::parser::parse_game;
+    use crate::output::*;
+
+    #[test]
+    fn totals_from_league_handicap() {
+        let games = vec![
+            parse_game("9- 9- 9- 9- 9- 9- 9- 9- 9- 9-", Rules::new()).unwrap(),
+            parse_game("X X X X X X X X X XXX", Rules::new()).unwrap(),
+        ];
+        let mut handicap = Handicap::new();
+        handicap.cap = Some(50);
+
+        let card = Card::new("Alice", 150, &games, &handicap);
+
+        assert_eq!(card.game_handicap(), 50);
+        assert_eq!(card.game_total(1), handicap.game(&card.series, 1).unwrap());
+        assert_eq!(card.series_handicap(), 100);
+        assert_eq!(card.series_total(), handicap.series(&card.series));
+        assert_eq!(card.series_total(), 490);
+    }
+}
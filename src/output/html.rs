@@ -0,0 +1,120 @@
+use crate::output::scorecard::cells;
+use crate::output::{escape, Card};
+
+static STYLE: &str = "body { font-family: sans-serif; } \
+table { border-collapse: collapse; margin-bottom: 1em; } \
+th, td { border: 1px solid #000; padding: 2px 6px; text-align: center; } \
+td.marks { font-family: monospace; } td.total { text-align: right; font-weight: bold; }";
+
+// Self-contained HTML page with one scorecard table per bowler
+pub fn html(title: &str, cards: &[Card]) -> String {
+    let tables: Vec<String> = cards.iter().map(table).collect();
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n{}</body>\n</html>\n",
+        tables.concat(),
+        title = escape(title),
+    )
+}
+
+fn table(card: &Card) -> String {
+    let frames = card
+        .games
+        .iter()
+        .map(|game| game.rules().max_frames)
+        .max()
+        .unwrap_or(0);
+
+    let mut rows = vec![];
+    let numbers: String = (1..=frames).map(|n| format!("<th>{}</th>", n)).collect();
+    rows.push(format!(
+        "<tr><th>Game</th>{}<th>HDCP</th><th>Total</th></tr>",
+        numbers
+    ));
+
+    for (number, game) in card.games.iter().enumerate() {
+        let cells = cells(game);
+        let marks: String = cells
+            .iter()
+            .map(|cell| format!("<td class=\"marks\">{}</td>", escape(&cell.marks)))
+            .collect();
+        let totals: String = cells
+            .iter()
+            .map(|cell| format!("<td class=\"total\">{}</td>", cell.total))
+            .collect();
+        let missing = "<td></td>".repeat(frames as usize - cells.len());
+
+        rows.push(format!(
+            "<tr><th rowspan=\"2\">{}</th>{}{}<td rowspan=\"2\">{}</td><td rowspan=\"2\" class=\"total\">{}</td></tr>",
+            number + 1,
+            marks,
+            missing,
+            card.game_handicap(),
+            card.game_total(number)
+        ));
+        rows.push(format!("<tr>{}{}</tr>", totals, missing));
+    }
+
+    rows.push(format!(
+        "<tr><th colspan=\"{}\">Series</th><td>{}</td><td class=\"total\">{}</td></tr>",
+        frames + 1,
+        card.series_handicap(),
+        card.series_total()
+    ));
+
+    format!(
+        "<h2>{}</h2>\n<table>\n{}\n</table>\n",
+        escape(&card.series.bowler),
+        rows.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::league::handicap::Handicap;
+    use crate::notation::parser::parse_game;
+    use crate::output::html::*;
+
+    #[test]
+    fn series_page() {
+        let games = vec![
+            parse_game("X 7/ 9- X -8 8/ -6 X X X81", Rules::new()).unwrap(),
+            parse_game("9- 9- 9- 9- 9- 9- 9- 9- 9- 9-", Rules::new()).unwrap(),
+        ];
+        let mut handicap = Handicap::new();
+        handicap.percentage = 100;
+        let cards = [Card::new("Alice", 200, &games, &handicap)];
+
+        let html = html("Week 1 <League>", &cards);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Week 1 &lt;League&gt;</title>"));
+        assert!(html.contains("<h2>Alice</h2>"));
+        assert!(html.contains("<td class=\"marks\">X 8 1</td>"));
+        assert!(
+            html.contains("<td rowspan=\"2\">20</td><td rowspan=\"2\" class=\"total\">187</td>")
+        );
+        assert!(
+            html.contains("<th colspan=\"11\">Series</th><td>40</td><td class=\"total\">297</td>")
+        );
+    }
+
+    #[test]
+    fn games_with_fewer_frames() {
+        let mut rules = Rules::new();
+        rules.max_frames = 2;
+        let games = vec![
+            parse_game("X X", Rules::new()).unwrap(),
+            parse_game("X X", rules).unwrap(),
+        ];
+        let handicap = Handicap::new();
+        let cards = [Card::new("Alice", 220, &games, &handicap)];
+
+        let html = html("Mixed", &cards);
+
+        assert_eq!(html.matches("<td class=\"marks\">").count(), 12);
+        assert_eq!(html.matches("<td></td>").count(), 16);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::game::Game;
+use crate::output::scorecard::{cells, row, widths, Cell};
+
+static NAME_HEADER: &str = "Bowler";
+static TOTAL_HEADER: &str = "Total";
+static ACTIVE_MARKER: &str = "> ";
+static BOLD: &str = "\x1b[1m";
+static REVERSE: &str = "\x1b[7m";
+static RESET: &str = "\x1b[0m";
+
+// Games stacked like an overhead monitor; the active bowler's current frame
+// is highlighted
+#[derive(Debug, Default)]
+pub struct Scoreboard {
+    pub active: Option<usize>,
+    pub color: bool,
+}
+
+impl Scoreboard {
+    pub fn new() -> Scoreboard {
+        Scoreboard::default()
+    }
+
+    pub fn render(&self, bowlers: &[(&str, &Game)]) -> String {
+        let cells: Vec<Vec<Cell>> = bowlers.iter().map(|(_, game)| cells(game)).collect();
+        let frames = cells.iter().map(|c| c.len()).max().unwrap_or(0);
+
+        // name column, one column per frame, total column
+        let mut columns = vec![NAME_HEADER.len() + ACTIVE_MARKER.len()];
+        columns.extend(vec![0; frames]);
+        columns.push(TOTAL_HEADER.len());
+        for (bowler, (name, game)) in bowlers.iter().enumerate() {
+            columns[0] = columns[0].max(name.len() + ACTIVE_MARKER.len());
+            for (frame, width) in widths(game, &cells[bowler]).into_iter().enumerate() {
+                columns[frame + 1] = columns[frame + 1].max(width);
+            }
+            columns[frames + 1] = columns[frames + 1].max(game.score().to_string().len());
+        }
+
+        let current_frame = self
+            .active
+            .and_then(|active| bowlers.get(active))
+            .map(|(_, game)| game.current_frame() as usize);
+
+        let border = row(&columns, |_, width| "-".repeat(width), '+', '-');
+        let header = row(
+            &columns,
+            |column, width| match column {
+                0 => format!("{:<width$}", NAME_HEADER),
+                _ if column == frames + 1 => format!("{:>width$}", TOTAL_HEADER),
+                _ if Some(column) == current_frame && !self.color => {
+                    format!("{:^width$}", format!("[{}]", column))
+                }
+                _ => format!("{:^width$}", column),
+            },
+            '|',
+            ' ',
+        );
+
+        let mut lines = vec![border.clone(), header, border.clone()];
+        for (bowler, (name, game)) in bowlers.iter().enumerate() {
+            let active = self.active == Some(bowler);
+            let cell = |frame: usize| cells[bowler].get(frame - 1);
+
+            let marks = row(
+                &columns,
+                |column, width| {
+                    let text = match column {
+                        0 if active => format!("{:<width$}", format!("{}{}", ACTIVE_MARKER, name)),
+                        0 => format!("{:<width$}", name),
+                        _ if column == frames + 1 => format!("{:>width$}", game.score()),
+                        _ => format!("{:<width$}", cell(column).map_or("", |c| &c.marks)),
+                    };
+                    self.highlight(text, active, active && Some(column) == current_frame)
+                },
+                '|',
+                ' ',
+            );
+            let totals = row(
+                &columns,
+                |column, width| {
+                    let text = match column {
+                        0 => " ".repeat(width),
+                        _ if column == frames + 1 => " ".repeat(width),
+                        _ => format!("{:>width$}", cell(column).map_or("", |c| &c.total)),
+                    };
+                    self.highlight(text, active, active && Some(column) == current_frame)
+                },
+                '|',
+                ' ',
+            );
+
+            lines.extend([marks, totals, border.clone()]);
+        }
+
+        lines.join("\n")
+    }
+
+    fn highlight(&self, text: String, active: bool, current: bool) -> String {
+        if !self.color {
+            text
+        } else if current {
+            format!("{}{}{}", REVERSE, text, RESET)
+        } else if active {
+            format!("{}{}{}", BOLD, text, RESET)
+        } else {
+            text
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::notation::parser::parse_game;
+    use crate::output::scoreboard::*;
+
+    #[test]
+    fn stacked_games() {
+        let mut rules = Rules::new();
+        rules.max_frames = 3;
+        let alice = parse_game("X 7/ 9-", rules.clone()).unwrap();
+        let bob = parse_game("81 X", rules).unwrap();
+
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.active = Some(1);
+
+        assert_eq!(
+            scoreboard.render(&[("Alice", &alice), ("Bob", &bob)]),
+            "\
++----------+-----+-----+---------+-------+
+| Bowler   |  1  |  2  |   [3]   | Total |
++----------+-----+-----+---------+-------+
+| Alice    | X   | 7 / | 9 -     |    48 |
+|          |  20 |  39 |      48 |       |
++----------+-----+-----+---------+-------+
+| > Bob    | 8 1 | X   |         |    19 |
+|          |   9 |     |         |       |
++----------+-----+-----+---------+-------+"
+        );
+    }
+
+    #[test]
+    fn colored_active_bowler() {
+        let mut rules = Rules::new();
+        rules.max_frames = 2;
+        let game = parse_game("X", rules).unwrap();
+
+        let scoreboard = Scoreboard {
+            active: Some(0),
+            color: true,
+        };
+        let board = scoreboard.render(&[("Ann", &game)]);
+
+        assert!(board.contains("\x1b[1m> Ann   \x1b[0m"));
+        assert!(board.contains("\x1b[7m       \x1b[0m"));
+        assert!(board.contains("|    2    |"));
+    }
+
+    #[test]
+    fn frames_of_any_rules() {
+        let mut rules = Rules::new();
+        rules.max_frames = 12;
+        let game = Game::new(rules);
+
+        let board = Scoreboard::new().render(&[("Ann", &game)]);
+
+        assert!(board.contains("| 11  |   12    | Total |"));
+        assert_eq!(board.lines().count(), 6);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::game::scoring::frame_totals;
+use crate::game::Game;
+use crate::notation::formatter::format_mark;
+
+// Frame number, marks and cumulative total of every frame, not yet rolled
+// frames included
+pub struct Cell {
+    pub number: String,
+    pub marks: String,
+    pub total: String,
+}
+
+pub fn cells(game: &Game) -> Vec<Cell> {
+    let frames = game.frames();
+    let totals = frame_totals(frames, game.rules());
+
+    (0..game.rules().max_frames as usize)
+        .map(|index| Cell {
+            number: (index + 1).to_string(),
+            marks: frames
+                .get(index)
+                .map(|frame| {
+                    frame
+                        .marks()
+                        .iter()
+                        .map(format_mark)
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                })
+                .unwrap_or_default(),
+            total: totals
+                .get(index)
+                .copied()
+                .flatten()
+                .map(|total| total.to_string())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+// Width of every frame's cell: room for all the marks of the frame
+pub fn widths(game: &Game, cells: &[Cell]) -> Vec<usize> {
+    let rules = game.rules();
+    cells
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            let mut marks = rules.rolls_per_frame as usize;
+            if index + 1 == rules.max_frames as usize && rules.fill_balls {
+                marks += 2;
+            }
+            [
+                marks * 2 - 1,
+                cell.number.len(),
+                cell.marks.len(),
+                cell.total.len(),
+            ]
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+        })
+        .collect()
+}
+
+// Classic scorecard: frame numbers, marks and cumulative totals
+pub fn scorecard(game: &Game) -> String {
+    let cells = cells(game);
+    let widths = widths(game, &cells);
+
+    let border = row(&widths, |_, width| "-".repeat(width), '+', '-');
+    let numbers = row(
+        &widths,
+        |index, width| format!("{:^width$}", cells[index].number),
+        '|',
+        ' ',
+    );
+    let marks = row(
+        &widths,
+        |index, width| format!("{:<width$}", cells[index].marks),
+        '|',
+        ' ',
+    );
+    let totals = row(
+        &widths,
+        |index, width| format!("{:>width$}", cells[index].total),
+        '|',
+        ' ',
+    );
+
+    [
+        border.clone(),
+        numbers,
+        border.clone(),
+        marks,
+        totals,
+        border,
+    ]
+    .join("\n")
+}
+
+pub(crate) fn row(
+    widths: &[usize],
+    cell: impl Fn(usize, usize) -> String,
+    separator: char,
+    padding: char,
+) -> String {
+    let cells: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(index, &width)| format!("{}{}{}", padding, cell(index, width), padding))
+        .collect();
+    format!(
+        "{}{}{}",
+        separator,
+        cells.join(&separator.to_string()),
+        separator
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::notation::parser::parse_game;
+    use crate::output::scorecard::*;
+
+    #[test]
+    fn complete_game() {
+        let game = parse_game("X 7/ 9- X -8 8/ -6 X X X81", Rules::new()).unwrap();
+
+        assert_eq!(
+            scorecard(&game),
+            "\
++-----+-----+-----+-----+-----+-----+-----+-----+-----+---------+
+|  1  |  2  |  3  |  4  |  5  |  6  |  7  |  8  |  9  |   10    |
++-----+-----+-----+-----+-----+-----+-----+-----+-----+---------+
+| X   | 7 / | 9 - | X   | - 8 | 8 / | - 6 | X   | X   | X 8 1   |
+|  20 |  39 |  48 |  66 |  74 |  84 |  90 | 120 | 148 |     167 |
++-----+-----+-----+-----+-----+-----+-----+-----+-----+---------+"
+        );
+    }
+
+    #[test]
+    fn pending_totals() {
+        let game = parse_game("X 7", Rules::new()).unwrap();
+
+        let cells = cells(&game);
+
+        assert_eq!(cells.len(), 10);
+        assert_eq!(cells[0].marks, "X");
+        assert_eq!(cells[0].total, "");
+        assert_eq!(cells[1].marks, "7");
+        assert_eq!(cells[2].marks, "");
+    }
+
+    #[test]
+    fn custom_rules() {
+        let mut rules = Rules::new();
+        rules.max_frames = 3;
+        rules.rolls_per_frame = 3;
+        let game = parse_game("123 X 4/-", rules).unwrap();
+
+        assert_eq!(
+            scorecard(&game),
+            "\
++-------+-------+-----------+
+|   1   |   2   |     3     |
++-------+-------+-----------+
+| 1 2 3 | X     | 4 / -     |
+|     6 |    26 |           |
++-------+-------+-----------+"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::output::scorecard::cells;
+use crate::output::{escape, Card};
+
+static NAME_WIDTH: usize = 140;
+static FRAME_WIDTH: usize = 40;
+static LAST_FRAME_WIDTH: usize = 60;
+static TOTAL_WIDTH: usize = 50;
+static TITLE_HEIGHT: usize = 30;
+static GAME_HEIGHT: usize = 50;
+static MARGIN: usize = 10;
+
+// Self-contained SVG document with one scorecard per bowler: a box per
+// frame, then handicap and total of every game, and the series totals
+pub fn svg(cards: &[Card]) -> String {
+    let frames = cards
+        .iter()
+        .flat_map(|card| card.games.iter())
+        .map(|game| game.rules().max_frames as usize)
+        .max()
+        .unwrap_or(0);
+    let width = MARGIN * 2 + NAME_WIDTH + frames_width(frames) + TOTAL_WIDTH * 2;
+    let height = MARGIN * 2
+        + cards
+            .iter()
+            .map(|card| TITLE_HEIGHT + GAME_HEIGHT * card.games.len())
+            .sum::<usize>();
+
+    let mut elements = vec![];
+    let mut y = MARGIN;
+    for card in cards {
+        elements.push(text(
+            MARGIN,
+            y + TITLE_HEIGHT - 10,
+            "title",
+            &format!(
+                "{} - handicap {} - series {} ({} scratch)",
+                card.series.bowler,
+                card.game_handicap(),
+                card.series_total(),
+                card.scratch()
+            ),
+        ));
+        y += TITLE_HEIGHT;
+
+        for (number, game) in card.games.iter().enumerate() {
+            elements.push(text(
+                MARGIN,
+                y + GAME_HEIGHT / 2 + 5,
+                "name",
+                &format!("Game {}", number + 1),
+            ));
+
+            let last = game.rules().max_frames as usize;
+            for (index, cell) in cells(game).iter().enumerate() {
+                let x = MARGIN + NAME_WIDTH + index * FRAME_WIDTH;
+                let width = if index + 1 == last {
+                    LAST_FRAME_WIDTH
+                } else {
+                    FRAME_WIDTH
+                };
+                elements.push(rect(x, y, width, GAME_HEIGHT));
+                elements.push(text(x + 3, y + 12, "number", &cell.number));
+                elements.push(text(x + 3, y + 26, "marks", &cell.marks));
+                elements.push(text(x + width - 3, y + 44, "total", &cell.total));
+            }
+
+            let x = MARGIN + NAME_WIDTH + frames_width(frames);
+            elements.push(rect(x, y, TOTAL_WIDTH, GAME_HEIGHT));
+            elements.push(text(x + 3, y + 12, "number", "HDCP"));
+            elements.push(text(
+                x + TOTAL_WIDTH - 3,
+                y + 44,
+                "total",
+                &card.game_handicap().to_string(),
+            ));
+            let x = x + TOTAL_WIDTH;
+            elements.push(rect(x, y, TOTAL_WIDTH, GAME_HEIGHT));
+            elements.push(text(x + 3, y + 12, "number", "TOTAL"));
+            elements.push(text(
+                x + TOTAL_WIDTH - 3,
+                y + 44,
+                "total",
+                &card.game_total(number).to_string(),
+            ));
+
+            y += GAME_HEIGHT;
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n\
+         <style>text {{ font-family: monospace; font-size: 12px; }} .title {{ font-size: 16px; font-weight: bold; }} \
+         .number {{ font-size: 9px; fill: #666; }} .total {{ text-anchor: end; font-weight: bold; }} \
+         rect {{ fill: none; stroke: #000; }}</style>\n\
+         {}\n</svg>\n",
+        elements.join("\n")
+    )
+}
+
+// Width of the frame boxes, the last frame being wider
+fn frames_width(frames: usize) -> usize {
+    match frames {
+        0 => 0,
+        _ => (frames - 1) * FRAME_WIDTH + LAST_FRAME_WIDTH,
+    }
+}
+
+fn rect(x: usize, y: usize, width: usize, height: usize) -> String {
+    format!("<rect x=\"{x}\" y=\"{y}\" width=\"{width}\" height=\"{height}\"/>")
+}
+
+fn text(x: usize, y: usize, class: &str, content: &str) -> String {
+    format!(
+        "<text x=\"{x}\" y=\"{y}\" class=\"{class}\">{}</text>",
+        escape(content)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::league::handicap::Handicap;
+    use crate::notation::parser::parse_game;
+    use crate::output::svg::*;
+
+    #[test]
+    fn series_scorecard() {
+        let games = vec![
+            parse_game("X 7/ 9- X -8 8/ -6 X X X81", Rules::new()).unwrap(),
+            parse_game("9- 9- 9- 9- 9- 9- 9- 9- 9- 9-", Rules::new()).unwrap(),
+        ];
+        let mut handicap = Handicap::new();
+        handicap.percentage = 100;
+        let card = Card::new("Alice & Bob <team>", 200, &games, &handicap);
+
+        let svg = svg(&[card]);
+
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"680\" height=\"150\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(
+            ">Alice &amp; Bob &lt;team&gt; - handicap 20 - series 297 (257 scratch)</text>"
+        ));
+        assert!(svg.contains("class=\"marks\">X 8 1</text>"));
+        assert!(svg.contains("class=\"total\">187</text>"));
+        assert_eq!(svg.matches("<rect").count(), 24);
+    }
+
+    #[test]
+    fn no_cards() {
+        assert!(svg(&[]).contains("width=\"260\" height=\"20\""));
+    }
+}
@@ -0,0 +1,169 @@
+pub mod leaves;
+
+use crate::game::frame::Frame;
+use crate::game::Game;
+
+// Counters over the completed frames of a bowler's games; frames credited
+// without rolling are not counted
+#[derive(Debug, Default, PartialEq)]
+pub struct Statistics {
+    pub games: u16,
+    pub frames: u16,
+    pub strikes: u16,
+    pub spare_chances: u16,
+    pub spares: u16,
+    pub single_pin_chances: u16,
+    pub single_pin_spares: u16,
+    pub open_frames: u16,
+    pub first_ball_pins: u32,
+    pub fill_balls: u16,
+    pub fill_ball_pins: u32,
+}
+
+impl Statistics {
+    pub fn from_games(games: &[Game]) -> Statistics {
+        let mut statistics = Statistics::default();
+        for game in games {
+            statistics.add_game(game);
+        }
+        statistics
+    }
+
+    pub fn add_game(&mut self, game: &Game) {
+        self.games += 1;
+        for frame in completed_frames(game).iter().filter(|f| !f.credited) {
+            self.add_frame(frame);
+        }
+    }
+
+    pub fn strike_percentage(&self) -> f32 {
+        percentage(self.strikes, self.frames)
+    }
+
+    pub fn spare_conversion(&self) -> f32 {
+        percentage(self.spares, self.spare_chances)
+    }
+
+    pub fn single_pin_conversion(&self) -> f32 {
+        percentage(self.single_pin_spares, self.single_pin_chances)
+    }
+
+    pub fn first_ball_average(&self) -> f32 {
+        average(self.first_ball_pins, self.frames)
+    }
+
+    pub fn fill_ball_average(&self) -> f32 {
+        average(self.fill_ball_pins, self.fill_balls)
+    }
+
+    fn add_frame(&mut self, frame: &Frame) {
+        self.frames += 1;
+        self.first_ball_pins += frame.rolls.first().copied().unwrap_or(0) as u32;
+        self.fill_balls += frame.fill_balls.len() as u16;
+        self.fill_ball_pins += frame
+            .fill_balls
+            .iter()
+            .map(|&pins| pins as u32)
+            .sum::<u32>();
+
+        if frame.is_strike() {
+            self.strikes += 1;
+            return;
+        }
+
+        self.spare_chances += 1;
+        let single_pin = frame.pins > 0 && frame.rolls.first() == Some(&(frame.pins - 1));
+        if single_pin {
+            self.single_pin_chances += 1;
+        }
+
+        if frame.is_spare() {
+            self.spares += 1;
+            if single_pin {
+                self.single_pin_spares += 1;
+            }
+        } else {
+            self.open_frames += 1;
+        }
+    }
+}
+
+// All frames of a closed game, the ones before the current frame otherwise
+pub(crate) fn completed_frames(game: &Game) -> &[Frame] {
+    let frames = game.frames();
+    if game.closed() {
+        frames
+    } else {
+        &frames[..frames.len() - 1]
+    }
+}
+
+pub(crate) fn percentage(count: u16, total: u16) -> f32 {
+    if total == 0 {
+        return 0.0;
+    }
+    count as f32 * 100.0 / total as f32
+}
+
+fn average(pins: u32, rolls: u16) -> f32 {
+    if rolls == 0 {
+        return 0.0;
+    }
+    pins as f32 / rolls as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::stats::*;
+
+    #[test]
+    fn no_games() {
+        let statistics = Statistics::from_games(&[]);
+
+        assert_eq!(statistics.strike_percentage(), 0.0);
+        assert_eq!(statistics.spare_conversion(), 0.0);
+        assert_eq!(statistics.first_ball_average(), 0.0);
+    }
+
+    #[test]
+    fn strikes_and_spares() {
+        // X 9/ 9- 8/ 7-
+        let game = play_game(&[10, 9, 1, 9, 0, 8, 2, 7, 0], Rules::new());
+
+        let statistics = Statistics::from_games(&[game]);
+
+        assert_eq!(statistics.frames, 5);
+        assert_eq!(statistics.strike_percentage(), 20.0);
+        assert_eq!(statistics.spare_conversion(), 50.0);
+        assert_eq!(statistics.single_pin_chances, 2);
+        assert_eq!(statistics.single_pin_conversion(), 50.0);
+        assert_eq!(statistics.open_frames, 2);
+        assert_eq!(statistics.first_ball_average(), 8.6);
+    }
+
+    #[test]
+    fn current_frame_is_not_counted() {
+        let game = play_game(&[10, 9], Rules::new());
+
+        let statistics = Statistics::from_games(&[game]);
+
+        assert_eq!(statistics.frames, 1);
+        assert_eq!(statistics.spare_chances, 0);
+    }
+
+    #[test]
+    fn fill_balls() {
+        let perfect = play_game(&[10; 12], Rules::new());
+        let spares = play_game(&[5; 21], Rules::new());
+
+        let statistics = Statistics::from_games(&[perfect, spares]);
+
+        assert_eq!(statistics.games, 2);
+        assert_eq!(statistics.fill_balls, 3);
+        assert_eq!(statistics.fill_ball_average(), 25.0 / 3.0);
+        assert_eq!(statistics.strike_percentage(), 50.0);
+        assert_eq!(statistics.spare_conversion(), 100.0);
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::game::pin_set::PinSet;
+use crate::game::Game;
+use crate::stats::{completed_frames, percentage};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LeaveRecord {
+    pub faced: u16,
+    pub converted: u16,
+}
+
+impl LeaveRecord {
+    pub fn conversion(&self) -> f32 {
+        percentage(self.converted, self.faced)
+    }
+}
+
+// Leaves faced after the first ball and whether they were converted; only
+// rolls with pin-level data are known
+#[derive(Debug, Default)]
+pub struct LeaveDatabase {
+    leaves: HashMap<PinSet, LeaveRecord>,
+}
+
+impl LeaveDatabase {
+    pub fn from_games(games: &[Game]) -> LeaveDatabase {
+        let mut database = LeaveDatabase::default();
+        for game in games {
+            database.add_game(game);
+        }
+        database
+    }
+
+    pub fn add_game(&mut self, game: &Game) {
+        for frame in completed_frames(game) {
+            if frame.is_strike() {
+                continue;
+            }
+
+            if let Some(leave) = frame.first_ball_leave() {
+                let record = self.leaves.entry(leave).or_default();
+                record.faced += 1;
+                if frame.is_spare() {
+                    record.converted += 1;
+                }
+            }
+        }
+    }
+
+    // Exact leave, e.g. "7-10"
+    pub fn leave(&self, leave: PinSet) -> LeaveRecord {
+        self.leaves.get(&leave).copied().unwrap_or_default()
+    }
+
+    // Every leave with at least the given pins standing, most faced first
+    pub fn containing(&self, pins: PinSet) -> Vec<(PinSet, LeaveRecord)> {
+        self.filter(|leave| pins.without(*leave).is_empty())
+    }
+
+    pub fn splits(&self) -> Vec<(PinSet, LeaveRecord)> {
+        self.filter(|leave| leave.is_split())
+    }
+
+    fn filter(&self, predicate: impl Fn(&PinSet) -> bool) -> Vec<(PinSet, LeaveRecord)> {
+        let mut leaves: Vec<(PinSet, LeaveRecord)> = self
+            .leaves
+            .iter()
+            .filter(|(leave, _)| predicate(leave))
+            .map(|(leave, record)| (*leave, *record))
+            .collect();
+        leaves.sort_by_key(|(leave, record)| (std::cmp::Reverse(record.faced), leave.pins()));
+        leaves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::stats::leaves::*;
+
+    #[test]
+    fn converted_and_missed_leaves() {
+        let database = LeaveDatabase::from_games(&[play_this_game(&[
+            (&[1, 2, 3, 4, 5, 6, 8, 9], &[7, 10]),
+            (&[1, 2, 3, 4, 5, 6, 8, 9], &[7]),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9], &[10]),
+        ])]);
+
+        let split = database.leave("7-10".parse().unwrap());
+        assert_eq!(
+            split,
+            LeaveRecord {
+                faced: 2,
+                converted: 1
+            }
+        );
+        assert_eq!(split.conversion(), 50.0);
+        assert_eq!(database.leave("10".parse().unwrap()).converted, 1);
+        assert_eq!(database.leave("4".parse().unwrap()).faced, 0);
+    }
+
+    #[test]
+    fn query_by_pins() {
+        let database = LeaveDatabase::from_games(&[play_this_game(&[
+            (&[1, 2, 3, 4, 5, 6, 8, 9], &[7, 10]),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9], &[10]),
+            (&[1, 2, 4, 5, 7, 8, 9], &[]),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 10], &[9]),
+        ])]);
+
+        let leaves: Vec<String> = database
+            .containing("10".parse().unwrap())
+            .iter()
+            .map(|(leave, _)| leave.to_string())
+            .collect();
+        assert_eq!(leaves, vec!["3-6-10", "7-10", "10"]);
+
+        let leaves: Vec<String> = database
+            .containing("7-10".parse().unwrap())
+            .iter()
+            .map(|(leave, _)| leave.to_string())
+            .collect();
+        assert_eq!(leaves, vec!["7-10"]);
+
+        let splits = database.splits();
+        assert_eq!(splits.len(), 1);
+        assert_eq!(splits[0].0.to_string(), "7-10");
+    }
+
+    #[test]
+    fn query_with_more_pins_than_leaves() {
+        let database = LeaveDatabase::from_games(&[play_this_game(&[
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9], &[10]),
+            (&[1, 2, 3, 4, 5, 6, 8, 9, 10], &[7]),
+        ])]);
+
+        assert!(database.containing("7-10".parse().unwrap()).is_empty());
+    }
+
+    #[test]
+    fn rolls_without_pin_level_data() {
+        let mut game = Game::new(Rules::new());
+        game.roll(9);
+        game.roll(1);
+        game.roll(0);
+
+        assert!(LeaveDatabase::from_games(&[game])
+            .containing(PinSet::empty())
+            .is_empty());
+    }
+
+    // Knocked down pins of the two rolls of each frame
+    fn play_this_game(frames: &[(&[u8], &[u8])]) -> Game {
+        let mut game = Game::new(Rules::new());
+        for (first, second) in frames {
+            game.roll_pin_set(PinSet::from_pins(first));
+            game.roll_pin_set(PinSet::from_pins(second));
+        }
+        game
+    }
+}
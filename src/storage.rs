@@ -0,0 +1,84 @@
+pub mod file;
+pub mod memory;
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::game::Game;
+use crate::league::{Bowler, League, Session};
+use crate::BoxResult;
+
+pub type Id = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Kind {
+    Game,
+    Bowler,
+    League,
+    Session,
+}
+
+// Values kept as JSON by kind and id; saving again under the same id
+// replaces the value
+pub trait Storage {
+    fn write(&mut self, kind: Kind, id: Id, value: Value) -> BoxResult<()>;
+    fn read(&self, kind: Kind, id: Id) -> BoxResult<Option<Value>>;
+    // Sorted ids of the values saved for a kind
+    fn ids(&self, kind: Kind) -> BoxResult<Vec<Id>>;
+
+    fn save_game(&mut self, id: Id, game: &Game) -> BoxResult<()> {
+        self.write(Kind::Game, id, serde_json::to_value(game)?)
+    }
+
+    fn load_game(&self, id: Id) -> BoxResult<Option<Game>> {
+        let game: Option<Game> = from_value(self.read(Kind::Game, id)?)?;
+        if let Some(game) = &game {
+            game.verify()?;
+        }
+        Ok(game)
+    }
+
+    fn save_bowler(&mut self, id: Id, bowler: &Bowler) -> BoxResult<()> {
+        self.write(Kind::Bowler, id, serde_json::to_value(bowler)?)
+    }
+
+    fn load_bowler(&self, id: Id) -> BoxResult<Option<Bowler>> {
+        from_value(self.read(Kind::Bowler, id)?)
+    }
+
+    fn save_league(&mut self, id: Id, league: &League) -> BoxResult<()> {
+        self.write(Kind::League, id, serde_json::to_value(league)?)
+    }
+
+    fn load_league(&self, id: Id) -> BoxResult<Option<League>> {
+        from_value(self.read(Kind::League, id)?)
+    }
+
+    fn save_session(&mut self, id: Id, session: &Session) -> BoxResult<()> {
+        self.write(Kind::Session, id, serde_json::to_value(session)?)
+    }
+
+    fn load_session(&self, id: Id) -> BoxResult<Option<Session>> {
+        from_value(self.read(Kind::Session, id)?)
+    }
+}
+
+pub(crate) fn sorted_ids(values: &HashMap<(Kind, Id), Value>, kind: Kind) -> Vec<Id> {
+    let mut ids: Vec<Id> = values
+        .keys()
+        .filter(|(k, _)| *k == kind)
+        .map(|(_, id)| *id)
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+fn from_value<T: DeserializeOwned>(value: Option<Value>) -> BoxResult<Option<T>> {
+    match value {
+        Some(value) => Ok(Some(serde_json::from_value(value)?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::storage::{sorted_ids, Id, Kind, Storage};
+use crate::BoxResult;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Line {
+    kind: Kind,
+    id: Id,
+    value: Value,
+}
+
+// Append-only file of JSON lines, read back in full when opened: the last
+// line saved for a kind and id wins. A line cut short by a crash is dropped.
+#[derive(Debug)]
+pub struct FileStorage {
+    file: File,
+    values: HashMap<(Kind, Id), Value>,
+}
+
+impl FileStorage {
+    pub fn open<P: AsRef<Path>>(path: P) -> BoxResult<FileStorage> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        let mut values = HashMap::new();
+        for text in complete_lines(&mut file)? {
+            let line: Line = serde_json::from_str(&text)?;
+            values.insert((line.kind, line.id), line.value);
+        }
+
+        Ok(FileStorage { file, values })
+    }
+}
+
+// Lines of a file opened for appending; a last line without newline was cut
+// short while writing and is truncated away
+pub(crate) fn complete_lines(file: &mut File) -> BoxResult<Vec<String>> {
+    let mut text = String::new();
+    file.read_to_string(&mut text)?;
+
+    let lines: Vec<String> = text
+        .split_inclusive('\n')
+        .take_while(|line| line.ends_with('\n'))
+        .map(|line| line.to_string())
+        .collect();
+    let length: usize = lines.iter().map(|line| line.len()).sum();
+    if length < text.len() {
+        file.set_len(length as u64)?;
+    }
+    Ok(lines)
+}
+
+pub(crate) fn append_line(file: &mut File, line: &str) -> BoxResult<()> {
+    file.write_all(format!("{}\n", line).as_bytes())?;
+    file.sync_data()?;
+    Ok(())
+}
+
+impl Storage for FileStorage {
+    fn write(&mut self, kind: Kind, id: Id, value: Value) -> BoxResult<()> {
+        let line = Line { kind, id, value };
+        append_line(&mut self.file, &serde_json::to_string(&line)?)?;
+
+        self.values.insert((kind, id), line.value);
+        Ok(())
+    }
+
+    fn read(&self, kind: Kind, id: Id) -> BoxResult<Option<Value>> {
+        Ok(self.values.get(&(kind, id)).cloned())
+    }
+
+    fn ids(&self, kind: Kind) -> BoxResult<Vec<Id>> {
+        Ok(sorted_ids(&self.values, kind))
+    }
+}
+
+// Fresh file in the temporary directory, unique to the test process
+#[cfg(test)]
+pub(crate) fn temp_path(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "scoring_bowling_{}_{}.jsonl",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::game::rules::Rules;
+    use crate::game::Game;
+    use crate::league::Bowler;
+    use crate::storage::file::*;
+
+    #[test]
+    fn survives_reopening() {
+        let path = temp_path("reopen");
+        let mut game = Game::new(Rules::new());
+        game.roll(7);
+
+        let mut storage = FileStorage::open(&path).unwrap();
+        storage.save_game(1, &game).unwrap();
+        storage.save_bowler(1, &Bowler::new("Alice", 180)).unwrap();
+        drop(storage);
+
+        let storage = FileStorage::open(&path).unwrap();
+        let mut resumed = storage.load_game(1).unwrap().unwrap();
+        resumed.roll(3);
+
+        assert_eq!(resumed.standing_pins(), 10);
+        assert_eq!(resumed.frames()[0].rolls, vec![7, 3]);
+        assert_eq!(
+            storage.load_bowler(1).unwrap(),
+            Some(Bowler::new("Alice", 180))
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn last_save_wins() {
+        let path = temp_path("last_save");
+
+        let mut storage = FileStorage::open(&path).unwrap();
+        storage.save_bowler(1, &Bowler::new("Alice", 180)).unwrap();
+        storage.save_bowler(1, &Bowler::new("Alice", 185)).unwrap();
+        drop(storage);
+
+        let storage = FileStorage::open(&path).unwrap();
+
+        assert_eq!(storage.load_bowler(1).unwrap().unwrap().average, 185);
+        assert_eq!(storage.ids(Kind::Bowler).unwrap(), vec![1]);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn drops_line_cut_short() {
+        let path = temp_path("cut_short");
+
+        let mut storage = FileStorage::open(&path).unwrap();
+        storage.save_bowler(1, &Bowler::new("Alice", 180)).unwrap();
+        drop(storage);
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"kind\":\"Bowler\",\"id\":2,").unwrap();
+
+        let mut storage = FileStorage::open(&path).unwrap();
+        storage.save_bowler(3, &Bowler::new("Bob", 150)).unwrap();
+        drop(storage);
+
+        let storage = FileStorage::open(&path).unwrap();
+        assert_eq!(storage.ids(Kind::Bowler).unwrap(), vec![1, 3]);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::storage::{sorted_ids, Id, Kind, Storage};
+use crate::BoxResult;
+
+// Nothing survives the process: for tests and one-off sessions
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    values: HashMap<(Kind, Id), Value>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> MemoryStorage {
+        MemoryStorage::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn write(&mut self, kind: Kind, id: Id, value: Value) -> BoxResult<()> {
+        self.values.insert((kind, id), value);
+        Ok(())
+    }
+
+    fn read(&self, kind: Kind, id: Id) -> BoxResult<Option<Value>> {
+        Ok(self.values.get(&(kind, id)).cloned())
+    }
+
+    fn ids(&self, kind: Kind) -> BoxResult<Vec<Id>> {
+        Ok(sorted_ids(&self.values, kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::rules::Rules;
+    use crate::game::Game;
+    use crate::league::{Bowler, League, Session};
+    use crate::storage::memory::*;
+
+    #[test]
+    fn save_and_load() {
+        let mut storage = MemoryStorage::new();
+        let mut game = Game::new(Rules::new());
+        game.roll(10);
+        let mut session = Session::new(1, 3);
+        session.add(7, 42);
+
+        storage.save_game(42, &game).unwrap();
+        storage.save_bowler(7, &Bowler::new("Alice", 180)).unwrap();
+        storage
+            .save_league(1, &League::new("Tuesday Mixed", Rules::three_six_nine()))
+            .unwrap();
+        storage.save_session(1, &session).unwrap();
+
+        assert_eq!(storage.load_game(42).unwrap().unwrap().rolls(), vec![10]);
+        assert_eq!(
+            storage.load_bowler(7).unwrap(),
+            Some(Bowler::new("Alice", 180))
+        );
+        assert_eq!(
+            storage
+                .load_league(1)
+                .unwrap()
+                .unwrap()
+                .rules
+                .auto_strike_frames,
+            vec![3, 6, 9]
+        );
+        assert_eq!(storage.load_session(1).unwrap(), Some(session));
+    }
+
+    #[test]
+    fn missing_value() {
+        let mut storage = MemoryStorage::new();
+        storage.save_bowler(7, &Bowler::new("Alice", 180)).unwrap();
+
+        assert!(storage.load_bowler(8).unwrap().is_none());
+        assert!(storage.load_game(7).unwrap().is_none());
+    }
+
+    #[test]
+    fn invalid_game() {
+        let mut storage = MemoryStorage::new();
+        let mut game = serde_json::to_value(Game::new(Rules::new())).unwrap();
+        game["current_frame"] = 0.into();
+        storage.write(Kind::Game, 1, game).unwrap();
+
+        assert!(storage.load_game(1).is_err());
+    }
+
+    #[test]
+    fn ids_by_kind() {
+        let mut storage = MemoryStorage::new();
+        storage.save_bowler(9, &Bowler::new("Bob", 150)).unwrap();
+        storage.save_bowler(2, &Bowler::new("Alice", 180)).unwrap();
+        storage.save_game(5, &Game::new(Rules::new())).unwrap();
+
+        assert_eq!(storage.ids(Kind::Bowler).unwrap(), vec![2, 9]);
+        assert_eq!(storage.ids(Kind::Game).unwrap(), vec![5]);
+    }
+}
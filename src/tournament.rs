@@ -0,0 +1,77 @@
+pub mod bracket;
+pub mod qualifying;
+pub mod roll_off;
+pub mod stepladder;
+
+use std::cmp::Ordering;
+
+use crate::game::rules::Variant;
+use crate::game::Game;
+use crate::BoxResult;
+
+// Entrants are referred to by their index in the tournament's seed list
+#[derive(Debug, PartialEq)]
+pub struct Match {
+    pub first: usize,
+    pub second: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Decision {
+    First,
+    Second,
+    Tie,
+}
+
+// Compare two finished games; a tie must be resolved by a roll-off
+pub fn decide(first: &Game, second: &Game) -> BoxResult<Decision> {
+    if !first.closed() || !second.closed() {
+        bail!("game not finished");
+    }
+
+    let mut ordering = first.score().cmp(&second.score());
+    if first.rules().variant == Variant::LowBall {
+        ordering = ordering.reverse();
+    }
+
+    Ok(match ordering {
+        Ordering::Greater => Decision::First,
+        Ordering::Less => Decision::Second,
+        Ordering::Equal => Decision::Tie,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::tournament::*;
+
+    #[test]
+    fn higher_score_wins() {
+        let first = play_game(&[4; 20], Rules::new());
+        let second = play_game(&[3; 20], Rules::new());
+
+        assert_eq!(decide(&first, &second).unwrap(), Decision::First);
+        assert_eq!(decide(&second, &first).unwrap(), Decision::Second);
+    }
+
+    #[test]
+    fn lower_score_wins_in_low_ball() {
+        let mut rules = Rules::new();
+        rules.variant = Variant::LowBall;
+        let mut first = Game::new(rules);
+        for _ in 0..20 {
+            first.roll(1);
+        }
+
+        let mut rules = Rules::new();
+        rules.variant = Variant::LowBall;
+        let mut second = Game::new(rules);
+        for _ in 0..20 {
+            second.roll(2);
+        }
+
+        assert_eq!(decide(&first, &second).unwrap(), Decision::First);
+    }
+}
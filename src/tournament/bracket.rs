@@ -0,0 +1,199 @@
+use crate::game::Game;
+use crate::tournament::{decide, Decision, Match};
+use crate::BoxResult;
+
+// Single elimination of one-game matches. Seeds are ordered by position,
+// index 0 being the top seed; top seeds get the byes when entrants are not
+// a power of two.
+#[derive(Debug, Default)]
+pub struct Bracket {
+    pub seeds: Vec<String>,
+    round: Vec<Option<usize>>,
+    winners: Vec<Option<usize>>,
+}
+
+impl Bracket {
+    pub fn new(seeds: Vec<String>) -> Bracket {
+        let round = bracket_order(seeds.len().next_power_of_two())
+            .into_iter()
+            .map(|seed| if seed < seeds.len() { Some(seed) } else { None })
+            .collect();
+
+        let mut bracket = Bracket {
+            seeds,
+            round,
+            winners: vec![],
+        };
+        bracket.start_round();
+        bracket
+    }
+
+    // Matches still to be bowled in the current round, by slot
+    pub fn pending(&self) -> Vec<(usize, Match)> {
+        self.winners
+            .iter()
+            .enumerate()
+            .filter(|(_, winner)| winner.is_none())
+            .filter_map(|(slot, _)| {
+                self.entrants(slot)
+                    .map(|(first, second)| (slot, Match { first, second }))
+            })
+            .collect()
+    }
+
+    // Games of the match in `slot`; on a tie the match stays pending and the
+    // roll-off games have to be recorded
+    pub fn record(&mut self, slot: usize, first: &Game, second: &Game) -> BoxResult<Decision> {
+        let (first_seed, second_seed) = match self.entrants(slot) {
+            Some(entrants) if self.winners[slot].is_none() => entrants,
+            _ => bail!("no pending match in slot"),
+        };
+
+        let decision = decide(first, second)?;
+        match decision {
+            Decision::First => self.winners[slot] = Some(first_seed),
+            Decision::Second => self.winners[slot] = Some(second_seed),
+            Decision::Tie => return Ok(decision),
+        }
+
+        if self.winners.iter().all(|winner| winner.is_some()) {
+            self.round = self.winners.clone();
+            self.start_round();
+        }
+
+        Ok(decision)
+    }
+
+    pub fn champion(&self) -> Option<usize> {
+        match self.round.as_slice() {
+            [champion] => *champion,
+            _ => None,
+        }
+    }
+
+    fn entrants(&self, slot: usize) -> Option<(usize, usize)> {
+        match (self.round.get(slot * 2), self.round.get(slot * 2 + 1)) {
+            (Some(Some(first)), Some(Some(second))) => Some((*first, *second)),
+            _ => None,
+        }
+    }
+
+    fn start_round(&mut self) {
+        while self.round.len() > 1 {
+            // byes advance straight away
+            self.winners = self
+                .round
+                .chunks(2)
+                .map(|pair| match pair {
+                    [Some(_), Some(_)] => None,
+                    [first, second] => first.or(*second),
+                    _ => None,
+                })
+                .collect();
+
+            if self.winners.iter().any(|winner| winner.is_none()) {
+                break;
+            }
+            self.round = self.winners.clone();
+        }
+    }
+}
+
+// Seed positions so that 1 meets 2 only in the final: 1v8, 4v5, 2v7, 3v6
+fn bracket_order(size: usize) -> Vec<usize> {
+    let mut order = vec![0];
+    while order.len() < size {
+        let count = order.len() * 2;
+        order = order.iter().flat_map(|s| [*s, count - 1 - s]).collect();
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::tournament::bracket::*;
+
+    #[test]
+    fn seeding_order() {
+        assert_eq!(bracket_order(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    fn top_seeds_get_byes() {
+        let bracket = Bracket::new(seeds(6));
+
+        assert_eq!(
+            bracket.pending(),
+            vec![
+                (
+                    1,
+                    Match {
+                        first: 3,
+                        second: 4
+                    }
+                ),
+                (
+                    3,
+                    Match {
+                        first: 2,
+                        second: 5
+                    }
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn winners_advance_to_champion() {
+        let mut bracket = Bracket::new(seeds(4));
+        let high = play_game(&[4; 20], Rules::new());
+        let low = play_game(&[3; 20], Rules::new());
+
+        bracket.record(0, &high, &low).unwrap();
+        assert_eq!(bracket.champion(), None);
+        bracket.record(1, &low, &high).unwrap();
+
+        assert_eq!(
+            bracket.pending(),
+            vec![(
+                0,
+                Match {
+                    first: 0,
+                    second: 2
+                }
+            )]
+        );
+
+        bracket.record(0, &low, &high).unwrap();
+        assert_eq!(bracket.champion(), Some(2));
+        assert!(bracket.pending().is_empty());
+    }
+
+    #[test]
+    fn tie_keeps_match_pending() {
+        let mut bracket = Bracket::new(seeds(2));
+        let game = play_game(&[4; 20], Rules::new());
+
+        assert_eq!(bracket.record(0, &game, &game).unwrap(), Decision::Tie);
+        assert_eq!(bracket.pending().len(), 1);
+        assert_eq!(bracket.champion(), None);
+    }
+
+    #[test]
+    fn no_pending_match() {
+        let mut bracket = Bracket::new(seeds(3));
+        let game = play_game(&[4; 20], Rules::new());
+
+        let decision = bracket.record(0, &game, &game);
+        assert_eq!(
+            decision.unwrap_err().to_string(),
+            "no pending match in slot"
+        );
+    }
+
+    fn seeds(count: usize) -> Vec<String> {
+        (1..=count).map(|n| format!("Seed {}", n)).collect()
+    }
+}
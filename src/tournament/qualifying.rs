@@ -0,0 +1,159 @@
+use crate::game::Game;
+use crate::tournament::Match;
+use crate::BoxResult;
+
+#[derive(Debug, Default)]
+pub struct Entry {
+    pub name: String,
+    pub scores: Vec<u16>,
+    pub carry_over: u16,
+}
+
+impl Entry {
+    pub fn new(name: &str) -> Entry {
+        Entry {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn total(&self) -> u16 {
+        self.carry_over + self.scores.iter().sum::<u16>()
+    }
+}
+
+// A block of qualifying games ranked by cumulative pinfall
+#[derive(Debug, Default)]
+pub struct Qualifying {
+    pub entries: Vec<Entry>,
+}
+
+impl Qualifying {
+    pub fn new(names: &[&str]) -> Qualifying {
+        Qualifying {
+            entries: names.iter().map(|name| Entry::new(name)).collect(),
+        }
+    }
+
+    pub fn record(&mut self, entry: usize, game: &Game) -> BoxResult<()> {
+        if !game.closed() {
+            bail!("game not finished");
+        }
+
+        match self.entries.get_mut(entry) {
+            Some(entry) => entry.scores.push(game.score()),
+            None => bail!("unknown entry"),
+        }
+        Ok(())
+    }
+
+    // Entry indexes from first to last place; ties keep entry order
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut ranking: Vec<usize> = (0..self.entries.len()).collect();
+        ranking.sort_by_key(|&entry| std::cmp::Reverse(self.entries[entry].total()));
+        ranking
+    }
+
+    // Next block with the top entries, carrying over a percentage of their pins
+    pub fn cut(&self, top: usize, carry_over_percentage: u16) -> Qualifying {
+        let entries = self
+            .ranking()
+            .into_iter()
+            .take(top)
+            .map(|index| {
+                let entry = &self.entries[index];
+                Entry {
+                    name: entry.name.clone(),
+                    scores: vec![],
+                    carry_over: (entry.total() as u32 * carry_over_percentage as u32 / 100) as u16,
+                }
+            })
+            .collect();
+
+        Qualifying { entries }
+    }
+
+    // Position round: 1st vs 2nd, 3rd vs 4th, ...; odd last entry sits out
+    pub fn position_round(&self) -> Vec<Match> {
+        self.ranking()
+            .chunks_exact(2)
+            .map(|pair| Match {
+                first: pair[0],
+                second: pair[1],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::tournament::qualifying::*;
+
+    #[test]
+    fn ranking_by_cumulative_pinfall() {
+        let qualifying = qualify(&[&[1, 4], &[3, 3], &[4, 4], &[2, 1]]);
+
+        assert_eq!(qualifying.entries[2].total(), 160);
+        assert_eq!(qualifying.ranking(), vec![2, 1, 0, 3]);
+    }
+
+    #[test]
+    fn cut_with_carry_over() {
+        let qualifying = qualify(&[&[1, 4], &[3, 3], &[4, 4], &[2, 1]]);
+
+        let finals = qualifying.cut(2, 50);
+
+        assert_eq!(finals.entries.len(), 2);
+        assert_eq!(finals.entries[0].name, "Bowler 3");
+        assert_eq!(finals.entries[0].carry_over, 80);
+        assert_eq!(finals.entries[1].name, "Bowler 2");
+        assert_eq!(finals.entries[1].total(), 60);
+    }
+
+    #[test]
+    fn position_round_pairings() {
+        let qualifying = qualify(&[&[1, 4], &[3, 3], &[4, 4], &[2, 1], &[1, 1]]);
+
+        assert_eq!(
+            qualifying.position_round(),
+            vec![
+                Match {
+                    first: 2,
+                    second: 1
+                },
+                Match {
+                    first: 0,
+                    second: 3
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn unfinished_game_is_not_recorded() {
+        let mut qualifying = Qualifying::new(&["Alice"]);
+
+        let result = qualifying.record(0, &play_game(&[4; 19], Rules::new()));
+
+        assert_eq!(result.unwrap_err().to_string(), "game not finished");
+        assert!(qualifying.entries[0].scores.is_empty());
+    }
+
+    // Every game is made of 20 rolls of the same pins
+    fn qualify(games: &[&[u8]]) -> Qualifying {
+        let names: Vec<String> = (1..=games.len()).map(|n| format!("Bowler {}", n)).collect();
+        let names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+        let mut qualifying = Qualifying::new(&names);
+
+        for (entry, pins) in games.iter().enumerate() {
+            for pins in pins.iter() {
+                qualifying
+                    .record(entry, &play_game(&[*pins; 20], Rules::new()))
+                    .unwrap();
+            }
+        }
+        qualifying
+    }
+}
@@ -0,0 +1,130 @@
+use crate::game::rules::Rules;
+use crate::game::Game;
+use crate::tournament::{decide, Decision};
+use crate::BoxResult;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RollOffFormat {
+    // 9th and 10th frames, fill balls included
+    NinthAndTenth,
+    // a single ball each, no fill balls
+    OneBall,
+}
+
+impl RollOffFormat {
+    pub fn rules(&self) -> Rules {
+        let mut rules = Rules::new();
+        match self {
+            RollOffFormat::NinthAndTenth => {
+                rules.max_frames = 2;
+            }
+            RollOffFormat::OneBall => {
+                rules.max_frames = 1;
+                rules.rolls_per_frame = 1;
+                rules.fill_balls = false;
+            }
+        }
+        rules
+    }
+
+    pub fn new_game(&self) -> Game {
+        Game::new(self.rules())
+    }
+}
+
+// Sudden death: roll-offs are repeated until they are not tied
+#[derive(Debug)]
+pub struct RollOff {
+    pub format: RollOffFormat,
+    pub rounds: u8,
+}
+
+impl RollOff {
+    pub fn new(format: RollOffFormat) -> RollOff {
+        RollOff { format, rounds: 0 }
+    }
+
+    pub fn record(&mut self, first: &Game, second: &Game) -> BoxResult<Decision> {
+        let decision = decide(first, second)?;
+        self.rounds += 1;
+        Ok(decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::tournament::roll_off::*;
+    use crate::tournament::stepladder::Stepladder;
+
+    #[test]
+    fn one_ball_strike() {
+        let game = roll_off_game(RollOffFormat::OneBall, &[10]);
+
+        assert_eq!(game.score(), 10);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn one_ball_is_a_single_roll() {
+        let game = roll_off_game(RollOffFormat::OneBall, &[7]);
+
+        assert_eq!(game.score(), 7);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn ninth_and_tenth_with_fill_balls() {
+        let game = roll_off_game(RollOffFormat::NinthAndTenth, &[10, 10, 10, 10]);
+
+        assert_eq!(game.score(), 60);
+        assert!(game.closed());
+    }
+
+    #[test]
+    fn ninth_and_tenth_not_finished() {
+        let game = roll_off_game(RollOffFormat::NinthAndTenth, &[9, 0, 5, 5]);
+
+        assert_eq!(game.score(), 19);
+        assert!(!game.closed());
+    }
+
+    #[test]
+    fn sudden_death_until_decided() {
+        let mut roll_off = RollOff::new(RollOffFormat::OneBall);
+
+        let first = roll_off_game(roll_off.format, &[9]);
+        let second = roll_off_game(roll_off.format, &[9]);
+        assert_eq!(roll_off.record(&first, &second).unwrap(), Decision::Tie);
+
+        let first = roll_off_game(roll_off.format, &[8]);
+        let second = roll_off_game(roll_off.format, &[10]);
+        assert_eq!(roll_off.record(&first, &second).unwrap(), Decision::Second);
+        assert_eq!(roll_off.rounds, 2);
+    }
+
+    #[test]
+    fn resolves_stepladder_tie() {
+        let mut stepladder = Stepladder::new(vec!["First".to_string(), "Second".to_string()]);
+        let tied = play_game(&[4; 20], Rules::new());
+        assert_eq!(stepladder.record(&tied, &tied).unwrap(), Decision::Tie);
+
+        let format = RollOffFormat::NinthAndTenth;
+        let holder = roll_off_game(format, &[10, 9, 1, 5]);
+        let opponent = roll_off_game(format, &[9, 0, 7, 2]);
+
+        assert_eq!(
+            stepladder.record(&holder, &opponent).unwrap(),
+            Decision::First
+        );
+        assert_eq!(stepladder.champion(), Some(1));
+    }
+
+    fn roll_off_game(format: RollOffFormat, rolls: &[u8]) -> Game {
+        let mut game = format.new_game();
+        for pins in rolls {
+            game.roll(*pins);
+        }
+        game
+    }
+}
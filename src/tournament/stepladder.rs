@@ -0,0 +1,163 @@
+use crate::game::Game;
+use crate::tournament::{decide, Decision, Match};
+use crate::BoxResult;
+
+// Lowest seeds bowl first; each winner climbs a step to meet the next seed.
+// Seeds are ordered by position, index 0 being the top seed.
+#[derive(Debug, Default)]
+pub struct Stepladder {
+    pub seeds: Vec<String>,
+    holder: usize,
+    opponent: Option<usize>,
+}
+
+impl Stepladder {
+    pub fn new(seeds: Vec<String>) -> Stepladder {
+        let last = seeds.len().saturating_sub(1);
+        Stepladder {
+            holder: last,
+            opponent: last.checked_sub(1),
+            seeds,
+        }
+    }
+
+    // Next match: the ladder holder against the next higher seed
+    pub fn current_match(&self) -> Option<Match> {
+        self.opponent.map(|opponent| Match {
+            first: self.holder,
+            second: opponent,
+        })
+    }
+
+    // Games of the current match, first the holder's one; on a tie the match
+    // stays open and the roll-off games have to be recorded
+    pub fn record(&mut self, holder_game: &Game, opponent_game: &Game) -> BoxResult<Decision> {
+        let opponent = match self.opponent {
+            Some(opponent) => opponent,
+            None => bail!("stepladder already finished"),
+        };
+
+        let decision = decide(holder_game, opponent_game)?;
+        if decision == Decision::Tie {
+            return Ok(decision);
+        }
+
+        if decision == Decision::Second {
+            self.holder = opponent;
+        }
+        self.opponent = opponent.checked_sub(1);
+
+        Ok(decision)
+    }
+
+    // None while matches are left, and for a ladder without seeds
+    pub fn champion(&self) -> Option<usize> {
+        match self.opponent {
+            None if !self.seeds.is_empty() => Some(self.holder),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::play_game;
+    use crate::game::rules::Rules;
+    use crate::tournament::stepladder::*;
+
+    #[test]
+    fn lowest_seeds_bowl_first() {
+        let stepladder = Stepladder::new(seeds(5));
+
+        assert_eq!(
+            stepladder.current_match(),
+            Some(Match {
+                first: 4,
+                second: 3
+            })
+        );
+        assert_eq!(stepladder.champion(), None);
+    }
+
+    #[test]
+    fn winner_climbs_the_ladder() {
+        let mut stepladder = Stepladder::new(seeds(4));
+
+        let decision = stepladder.record(
+            &play_game(&[4; 20], Rules::new()),
+            &play_game(&[3; 20], Rules::new()),
+        );
+        assert_eq!(decision.unwrap(), Decision::First);
+        assert_eq!(
+            stepladder.current_match(),
+            Some(Match {
+                first: 3,
+                second: 1
+            })
+        );
+
+        let decision = stepladder.record(
+            &play_game(&[2; 20], Rules::new()),
+            &play_game(&[3; 20], Rules::new()),
+        );
+        assert_eq!(decision.unwrap(), Decision::Second);
+
+        let decision = stepladder.record(
+            &play_game(&[4; 20], Rules::new()),
+            &play_game(&[3; 20], Rules::new()),
+        );
+        assert_eq!(decision.unwrap(), Decision::First);
+
+        assert_eq!(stepladder.current_match(), None);
+        assert_eq!(stepladder.champion(), Some(1));
+    }
+
+    #[test]
+    fn tie_needs_a_roll_off() {
+        let mut stepladder = Stepladder::new(seeds(2));
+
+        let decision = stepladder.record(
+            &play_game(&[4; 20], Rules::new()),
+            &play_game(&[4; 20], Rules::new()),
+        );
+        assert_eq!(decision.unwrap(), Decision::Tie);
+        assert_eq!(stepladder.champion(), None);
+
+        let decision = stepladder.record(
+            &play_game(&[10; 12], Rules::new()),
+            &play_game(&[5; 21], Rules::new()),
+        );
+        assert_eq!(decision.unwrap(), Decision::First);
+        assert_eq!(stepladder.champion(), Some(1));
+    }
+
+    #[test]
+    fn no_seeds() {
+        let stepladder = Stepladder::new(vec![]);
+
+        assert_eq!(stepladder.current_match(), None);
+        assert_eq!(stepladder.champion(), None);
+    }
+
+    #[test]
+    fn unfinished_game() {
+        let mut stepladder = Stepladder::new(seeds(3));
+
+        let decision = stepladder.record(
+            &play_game(&[4; 19], Rules::new()),
+            &play_game(&[4; 20], Rules::new()),
+        );
+        assert_eq!(decision.unwrap_err().to_string(), "game not finished");
+        assert_eq!(
+            stepladder.current_match(),
+            Some(Match {
+                first: 2,
+                second: 1
+            })
+        );
+    }
+
+    fn seeds(count: usize) -> Vec<String> {
+        (1..=count).map(|n| format!("Seed {}", n)).collect()
+    }
+}